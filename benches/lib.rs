@@ -7,10 +7,7 @@
 
 extern crate test;
 
-use divrem::DivRem;
-use divrem::{DivCeil, DivRemCeil, RemCeil};
-use divrem::{DivEuclid, DivRemEuclid, RemEuclid};
-use divrem::{DivFloor, DivRemFloor, RemFloor};
+use divrem::prelude::*;
 use test::Bencher;
 
 #[inline]
@@ -162,6 +159,33 @@ fn div_rem_euclid1(self_: i32, other: i32) -> (i32, i32) {
     DivRemEuclid::div_rem_euclid(self_, other)
 }
 
+// 128-bit operands that fit in 64 bits take the fast paths, wide ones
+// (shifted left by 64 bits) don't.
+macro_rules! wide_functions {
+    ($($narrow:ident, $wide:ident = $trait:ident::$method:ident -> $output:ty;)*) => {$(
+        #[inline]
+        fn $narrow(self_: i128, other: i128) -> $output {
+            $trait::$method(self_, other)
+        }
+
+        #[inline]
+        fn $wide(self_: i128, other: i128) -> $output {
+            $trait::$method(self_ << 64, other)
+        }
+    )*};
+}
+
+wide_functions! {
+    div_rem_i128, div_rem_i128_wide = DivRem::div_rem -> (i128, i128);
+    div_floor_i128, div_floor_i128_wide = DivFloor::div_floor -> i128;
+    rem_floor_i128, rem_floor_i128_wide = RemFloor::rem_floor -> i128;
+    div_rem_floor_i128, div_rem_floor_i128_wide = DivRemFloor::div_rem_floor -> (i128, i128);
+    div_ceil_i128, div_ceil_i128_wide = DivCeil::div_ceil -> i128;
+    div_rem_ceil_i128, div_rem_ceil_i128_wide = DivRemCeil::div_rem_ceil -> (i128, i128);
+    div_euclid_i128, div_euclid_i128_wide = DivEuclid::div_euclid -> i128;
+    div_rem_euclid_i128, div_rem_euclid_i128_wide = DivRemEuclid::div_rem_euclid -> (i128, i128);
+}
+
 macro_rules! bench_loop_inner {
     ($function:expr) => {{
         let mut sum = 0;
@@ -219,6 +243,22 @@ bench!(bench_div_rem_ceil3, div_rem_ceil3, 2);
 bench!(bench_div_euclid1, div_euclid1, 1);
 bench!(bench_rem_euclid1, rem_euclid1, 1);
 bench!(bench_div_rem_euclid1, div_rem_euclid1, 2);
+bench!(bench_div_rem_i128, div_rem_i128, 2);
+bench!(bench_div_rem_i128_wide, div_rem_i128_wide, 2);
+bench!(bench_div_floor_i128, div_floor_i128, 1);
+bench!(bench_div_floor_i128_wide, div_floor_i128_wide, 1);
+bench!(bench_rem_floor_i128, rem_floor_i128, 1);
+bench!(bench_rem_floor_i128_wide, rem_floor_i128_wide, 1);
+bench!(bench_div_rem_floor_i128, div_rem_floor_i128, 2);
+bench!(bench_div_rem_floor_i128_wide, div_rem_floor_i128_wide, 2);
+bench!(bench_div_ceil_i128, div_ceil_i128, 1);
+bench!(bench_div_ceil_i128_wide, div_ceil_i128_wide, 1);
+bench!(bench_div_rem_ceil_i128, div_rem_ceil_i128, 2);
+bench!(bench_div_rem_ceil_i128_wide, div_rem_ceil_i128_wide, 2);
+bench!(bench_div_euclid_i128, div_euclid_i128, 1);
+bench!(bench_div_euclid_i128_wide, div_euclid_i128_wide, 1);
+bench!(bench_div_rem_euclid_i128, div_rem_euclid_i128, 2);
+bench!(bench_div_rem_euclid_i128_wide, div_rem_euclid_i128_wide, 2);
 
 macro_rules! test_algos_eq {
     ($test_name:ident, $functions:expr) => {
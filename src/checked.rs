@@ -0,0 +1,273 @@
+use core::ops::{Div, Neg, Rem};
+
+use crate::int::{self, DivInt};
+use crate::{
+    DivCeil, DivError, DivEuclid, DivFloor, DivRem, DivRemCeil, DivRemEuclid, DivRemFloor, RemCeil,
+    RemEuclid, RemFloor,
+};
+
+/// Checks that dividing `x` by `y` neither divides by zero nor overflows.
+///
+/// None of the division variants can overflow when the truncated
+/// division does not.
+#[inline]
+fn check<T: DivInt>(x: T, y: T) -> Result<(), DivError> {
+    match x.checked_div(y) {
+        Some(_) => Ok(()),
+        None if y == T::ZERO => Err(DivError::DivisionByZero),
+        None => Err(DivError::Overflow),
+    }
+}
+
+/// Division variants returning a `Result`.
+///
+/// Every method returns `Err(DivError::DivisionByZero)` if the divisor is
+/// zero and `Err(DivError::Overflow)` if the quotient does not fit in the
+/// type, even for `Wrapping` types. The remainder methods fail whenever the
+/// matching quotient would, as the `%` operator does.
+///
+/// `Saturating` types never overflow, their quotients saturate instead, so
+/// they only fail on division by zero.
+///
+/// This trait is implemented for all types implementing [`DivInt`].
+#[allow(clippy::missing_errors_doc)] // Errors are documented above.
+pub trait TryDivRem: DivInt {
+    /// Checked `/` operation.
+    #[inline]
+    fn try_div(self, other: Self) -> Result<Self, DivError> {
+        check(self, other).map(|()| self / other)
+    }
+
+    /// Checked `%` operation.
+    #[inline]
+    fn try_rem(self, other: Self) -> Result<Self, DivError> {
+        check(self, other).map(|()| int::rem(self, other))
+    }
+
+    /// Checked truncated division and remainder.
+    #[inline]
+    fn try_div_rem(self, other: Self) -> Result<(Self, Self), DivError> {
+        check(self, other).map(|()| self.div_rem(other))
+    }
+
+    /// Checked floored division.
+    #[inline]
+    fn try_div_floor(self, other: Self) -> Result<Self, DivError> {
+        check(self, other).map(|()| self.div_floor(other))
+    }
+
+    /// Checked floored division remainder.
+    #[inline]
+    fn try_rem_floor(self, other: Self) -> Result<Self, DivError> {
+        check(self, other).map(|()| self.rem_floor(other))
+    }
+
+    /// Checked floored division and remainder.
+    #[inline]
+    fn try_div_rem_floor(self, other: Self) -> Result<(Self, Self), DivError> {
+        check(self, other).map(|()| self.div_rem_floor(other))
+    }
+
+    /// Checked ceiled division.
+    #[inline]
+    fn try_div_ceil(self, other: Self) -> Result<Self, DivError> {
+        check(self, other).map(|()| DivCeil::div_ceil(self, other))
+    }
+
+    /// Checked ceiled division remainder.
+    #[inline]
+    fn try_rem_ceil(self, other: Self) -> Result<Self, DivError>
+    where Self: Neg<Output = Self> {
+        check(self, other).map(|()| self.rem_ceil(other))
+    }
+
+    /// Checked ceiled division and remainder.
+    #[inline]
+    fn try_div_rem_ceil(self, other: Self) -> Result<(Self, Self), DivError>
+    where Self: Neg<Output = Self> {
+        check(self, other).map(|()| self.div_rem_ceil(other))
+    }
+
+    /// Checked euclidean division.
+    #[inline]
+    fn try_div_euclid(self, other: Self) -> Result<Self, DivError> {
+        check(self, other).map(|()| DivEuclid::div_euclid(self, other))
+    }
+
+    /// Checked euclidean division remainder.
+    #[inline]
+    fn try_rem_euclid(self, other: Self) -> Result<Self, DivError> {
+        check(self, other).map(|()| RemEuclid::rem_euclid(self, other))
+    }
+
+    /// Checked euclidean division and remainder.
+    #[inline]
+    fn try_div_rem_euclid(self, other: Self) -> Result<(Self, Self), DivError> {
+        check(self, other).map(|()| self.div_rem_euclid(other))
+    }
+}
+
+impl<T: DivInt> TryDivRem for T {}
+
+/// An integer that becomes poisoned on division by zero or overflow.
+///
+/// `Checked<T>` chains checked divisions without handling an `Option` at
+/// every step, as `Wrapping<T>` chains wrapping operations. The division
+/// operators and every division variant of this crate return a poisoned
+/// value if either operand is poisoned or if the matching [`TryDivRem`]
+/// method fails. [`get`](Checked::get) returns the final value, if any.
+///
+/// ```
+/// use divrem::prelude::*;
+/// use divrem::Checked;
+///
+/// let x = Checked::new(-7_i32);
+/// assert_eq!(x.div_floor(Checked::new(2)).rem_euclid(Checked::new(3)).get(), Some(2));
+/// assert_eq!((x / Checked::new(0) / Checked::new(2)).get(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Checked<T>(Option<T>);
+
+impl<T> Checked<T> {
+    /// Wraps `value`.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Checked(Some(value))
+    }
+
+    /// Returns a poisoned value.
+    #[inline]
+    pub const fn poisoned() -> Self {
+        Checked(None)
+    }
+
+    /// Returns whether a division by zero or an overflow happened.
+    #[inline]
+    pub const fn is_poisoned(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns the value, or `None` if it is poisoned.
+    #[inline]
+    pub fn get(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> From<T> for Checked<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Checked::new(value)
+    }
+}
+
+/// Applies the `try_*` method `f` to the values of `x` and `y`.
+#[inline]
+fn apply<T, U>(x: Checked<T>, y: Checked<T>, f: fn(T, T) -> Result<U, DivError>) -> Option<U> {
+    f(x.0?, y.0?).ok()
+}
+
+/// Splits a checked quotient and remainder pair.
+#[inline]
+fn split<T: Copy>(pair: Option<(T, T)>) -> (Checked<T>, Checked<T>) {
+    (Checked(pair.map(|(q, _)| q)), Checked(pair.map(|(_, r)| r)))
+}
+
+macro_rules! impl_checked {
+    ($trait:ident($method:ident) -> $output:ty $(where T: $bound:ident)? { $($body:tt)* }) => {
+        impl<'a, T: DivInt $(+ $bound<Output = T>)?> $trait<Checked<T>> for &'a Checked<T> {
+            $($body)*
+
+            #[inline]
+            fn $method(self, other: Checked<T>) -> $output {
+                $trait::$method(*self, other)
+            }
+        }
+
+        impl<'a, T: DivInt $(+ $bound<Output = T>)?> $trait<&'a Checked<T>> for Checked<T> {
+            $($body)*
+
+            #[inline]
+            fn $method(self, other: &'a Checked<T>) -> $output {
+                $trait::$method(self, *other)
+            }
+        }
+
+        impl<'a, 'b, T: DivInt $(+ $bound<Output = T>)?> $trait<&'a Checked<T>> for &'b Checked<T> {
+            $($body)*
+
+            #[inline]
+            fn $method(self, other: &'a Checked<T>) -> $output {
+                $trait::$method(*self, *other)
+            }
+        }
+    };
+    (single: $($trait:ident($method:ident) = $try:ident $(where T: $bound:ident)?;)*) => {$(
+        impl<T: DivInt $(+ $bound<Output = T>)?> $trait for Checked<T> {
+            #[inline]
+            fn $method(self, other: Self) -> Self {
+                Checked(apply(self, other, T::$try))
+            }
+        }
+
+        impl_checked!($trait($method) -> Checked<T> $(where T: $bound)? {});
+    )*};
+    (pair: $($trait:ident($method:ident) = $try:ident $(where T: $bound:ident)?;)*) => {$(
+        impl<T: DivInt $(+ $bound<Output = T>)?> $trait for Checked<T> {
+            #[inline]
+            fn $method(self, other: Self) -> (Self, Self) {
+                split(apply(self, other, T::$try))
+            }
+        }
+
+        impl_checked!($trait($method) -> (Checked<T>, Checked<T>) $(where T: $bound)? {});
+    )*};
+}
+
+impl<T: DivInt> Div for Checked<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Checked(apply(self, other, T::try_div))
+    }
+}
+
+impl<T: DivInt> Rem for Checked<T> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, other: Self) -> Self {
+        Checked(apply(self, other, T::try_rem))
+    }
+}
+
+impl<T: DivInt> DivRem for Checked<T> {
+    type Output = (Self, Self);
+
+    #[inline]
+    fn div_rem(self, other: Self) -> (Self, Self) {
+        split(apply(self, other, T::try_div_rem))
+    }
+}
+
+impl_checked!(Div(div) -> Checked<T> { type Output = Checked<T>; });
+impl_checked!(Rem(rem) -> Checked<T> { type Output = Checked<T>; });
+impl_checked!(DivRem(div_rem) -> (Checked<T>, Checked<T>) {
+    type Output = (Checked<T>, Checked<T>);
+});
+
+impl_checked!(single:
+    DivFloor(div_floor) = try_div_floor;
+    RemFloor(rem_floor) = try_rem_floor;
+    DivCeil(div_ceil) = try_div_ceil;
+    RemCeil(rem_ceil) = try_rem_ceil where T: Neg;
+    DivEuclid(div_euclid) = try_div_euclid;
+    RemEuclid(rem_euclid) = try_rem_euclid;
+);
+
+impl_checked!(pair:
+    DivRemFloor(div_rem_floor) = try_div_rem_floor;
+    DivRemCeil(div_rem_ceil) = try_div_rem_ceil where T: Neg;
+    DivRemEuclid(div_rem_euclid) = try_div_rem_euclid;
+);
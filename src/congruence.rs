@@ -0,0 +1,157 @@
+//! Linear congruences and the Chinese remainder theorem.
+//!
+//! [`crt`] combines a system of congruences `x ≡ r (mod m)` into a single
+//! one and [`solve_linear_congruence`] solves `a * x ≡ b (mod m)`. The
+//! moduli need not be coprime. Residues are normalised into `[0, m)` with
+//! the euclidean remainder, as [`RemEuclid`] does, and intermediate
+//! products are reduced so that nothing overflows unless the result does
+//! not fit in the type.
+//!
+//! ```
+//! use divrem::congruence::{crt, solve_linear_congruence};
+//!
+//! // x ≡ 3 (mod 7) and x ≡ 5 (mod 12).
+//! assert_eq!(crt(&[(3, 7), (5, 12)]), Some((17, 84)));
+//! // x ≡ 1 (mod 4) and x ≡ 2 (mod 6) have no common solution.
+//! assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+//!
+//! // 4 * x ≡ 2 (mod 6).
+//! let solutions = solve_linear_congruence(4, 2, 6).unwrap();
+//! assert_eq!((solutions.residue(), solutions.modulus()), (2, 3));
+//! assert!(solutions.eq([2, 5]));
+//! ```
+
+use core::iter::FusedIterator;
+
+use crate::{DivInt, Gcd, RemEuclid};
+
+/// Returns `(r, m)` such that the common solutions of the congruences
+/// `x ≡ residue (mod modulus)` are the integers `x ≡ r (mod m)`, with `r`
+/// in `[0, m)` and `m` the least common multiple of the moduli.
+///
+/// Returns `None` if a modulus is not positive, if the congruences have no
+/// common solution or if `m` does not fit in the type. Every integer is a
+/// solution of an empty system, which returns `(0, 1)`.
+#[inline]
+pub fn crt<T: DivInt>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences.iter().try_fold((T::ZERO, T::ONE), |(r, m), &(residue, modulus)| {
+        if modulus <= T::ZERO {
+            return None;
+        }
+        merge(r, m, residue.rem_euclid(modulus), modulus)
+    })
+}
+
+/// Combines `x ≡ r1 (mod m1)` and `x ≡ r2 (mod m2)`, with normalised
+/// residues and positive moduli.
+#[inline]
+fn merge<T: DivInt>(r1: T, m1: T, r2: T, m2: T) -> Option<(T, T)> {
+    // `x = r1 + m1 * k` where `m1 * k ≡ r2 - r1 (mod m2)`, which has a
+    // solution only if `g` divides `r2 - r1`.
+    let g = m1.gcd(m2);
+    let n = m2 / g;
+    let (diff, negative) = if r2 >= r1 { (r2 - r1, false) } else { (r1 - r2, true) };
+    if diff % g != T::ZERO {
+        return None;
+    }
+    let diff = diff / g % n;
+    let diff = if negative && diff != T::ZERO { n - diff } else { diff };
+    let m = (m1 / g).checked_mul(m2)?;
+    let k = mul_mod(diff, (m1 / g).mod_inverse(n)?, n);
+    // `r1 < m1` and `k < n`, so `r1 + m1 * k < m1 * n == m`.
+    Some((r1 + m1 * k, m))
+}
+
+/// Solves `a * x ≡ b (mod m)`.
+///
+/// Returns `None` if `m` is not positive or if there is no solution, that
+/// is if `gcd(a, m)` does not divide `b`. Otherwise the solutions are the
+/// integers congruent to a single residue modulo `m / gcd(a, m)`, see
+/// [`Solutions`].
+#[inline]
+pub fn solve_linear_congruence<T: DivInt>(a: T, b: T, m: T) -> Option<Solutions<T>> {
+    if m <= T::ZERO {
+        return None;
+    }
+    let (a, b) = (a.rem_euclid(m), b.rem_euclid(m));
+    // `gcd(0, m)` is `m`, in which case every integer is a solution.
+    let gcd = a.gcd(m);
+    if b % gcd != T::ZERO {
+        return None;
+    }
+    let modulus = m / gcd;
+    let residue = mul_mod(b / gcd, (a / gcd).mod_inverse(modulus)?, modulus);
+    Some(Solutions { residue, modulus, next: Some(residue), end: m })
+}
+
+/// The solutions of a linear congruence, returned by
+/// [`solve_linear_congruence`].
+///
+/// The solutions of `a * x ≡ b (mod m)` are the integers
+/// `x ≡ residue (mod modulus)`, where `modulus` divides `m`. As an iterator,
+/// this yields the `m / modulus` solutions in `[0, m)` in increasing order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Solutions<T> {
+    residue: T,
+    modulus: T,
+    next: Option<T>,
+    end: T,
+}
+
+impl<T: DivInt> Solutions<T> {
+    /// Returns the smallest non-negative solution, in `[0, modulus)`.
+    #[inline]
+    #[must_use]
+    pub const fn residue(&self) -> T {
+        self.residue
+    }
+
+    /// Returns the modulus of the solutions, which is `m / gcd(a, m)`.
+    #[inline]
+    #[must_use]
+    pub const fn modulus(&self) -> T {
+        self.modulus
+    }
+}
+
+impl<T: DivInt> Iterator for Solutions<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let x = self.next?;
+        self.next = x.checked_add(self.modulus).filter(|&y| y < self.end);
+        Some(x)
+    }
+}
+
+impl<T: DivInt> FusedIterator for Solutions<T> {}
+
+/// Returns `a + b` modulo `n`, for `a` and `b` in `[0, n)`.
+#[inline]
+fn add_mod<T: DivInt>(a: T, b: T, n: T) -> T {
+    if a >= n - b {
+        a - (n - b)
+    } else {
+        a + b
+    }
+}
+
+/// Returns `a * b` modulo `n`, for `a` and `b` in `[0, n)`, by doubling
+/// and adding when the product overflows.
+#[inline]
+fn mul_mod<T: DivInt>(mut a: T, mut b: T, n: T) -> T {
+    if let Some(product) = a.checked_mul(b) {
+        return product % n;
+    }
+    let two = T::ONE + T::ONE;
+    let mut result = T::ZERO;
+    while b != T::ZERO {
+        if b % two == T::ONE {
+            result = add_mod(result, a, n);
+        }
+        a = add_mod(a, a, n);
+        b = b / two;
+    }
+    result
+}
@@ -0,0 +1,211 @@
+//! Continued fractions and best rational approximations.
+//!
+//! The continued fraction of `p / q` is `[a0; a1, a2, …]` where `a0` is
+//! `p.div_floor(q)` and the other terms are those of `q / r`, `r` being the
+//! floored remainder, until it is zero. The terms after the first are
+//! positive, whatever the signs of `p` and `q`.
+//!
+//! The convergents `h / k` are the fractions of the truncated expansions
+//! and the semiconvergents the fractions between them. The convergents
+//! approximate `p / q` better than any fraction with a smaller denominator,
+//! and the closest fraction with a bounded denominator is a semiconvergent,
+//! which [`best_approximation`] relies on. Their denominators are positive.
+//!
+//! The iterators end early if a value does not fit in the type, which only
+//! happens for fractions near `MIN` or `MAX`.
+//!
+//! ```
+//! use divrem::continued_fraction::{best_approximation, convergents, terms};
+//!
+//! // 415 / 93 = 4 + 1 / (2 + 1 / (6 + 1 / 7))
+//! assert!(terms(415, 93).eq([4, 2, 6, 7]));
+//! assert!(convergents(415, 93).eq([(4, 1), (9, 2), (58, 13), (415, 93)]));
+//! assert!(terms(-415, 93).eq([-5, 1, 1, 6, 7]));
+//!
+//! // 44100 / 48000 with a denominator of at most 100.
+//! assert_eq!(best_approximation(44100, 48000, 100), Some((79, 86)));
+//! ```
+
+use core::cmp::Ordering;
+
+use crate::{DivInt, DivRemFloor, TryDivRem};
+
+/// Returns an iterator over the terms of the continued fraction of `p / q`,
+/// which is empty if `q` is zero.
+#[inline]
+pub const fn terms<T: DivInt>(p: T, q: T) -> Terms<T> {
+    Terms { p, q }
+}
+
+/// Returns an iterator over the convergents `(h, k)` of `p / q`, the last of
+/// which is `p / q` in lowest terms.
+#[inline]
+pub const fn convergents<T: DivInt>(p: T, q: T) -> Convergents<T> {
+    Convergents { terms: terms(p, q), prev: (T::ZERO, T::ONE), last: (T::ONE, T::ZERO) }
+}
+
+/// Returns an iterator over the semiconvergents `(h, k)` of `p / q`, in
+/// increasing order of denominator except for the first two, `a0 / 1` and
+/// `(a0 + 1) / 1`.
+///
+/// After the integer part `a0 / 1`, each term `a` of the continued fraction
+/// yields the `a` fractions `(h0 + j * h1) / (k0 + j * k1)` for `j` in
+/// `1..=a`, where `h0 / k0` and `h1 / k1` are the previous two convergents.
+/// The last of them is the next convergent.
+#[inline]
+pub const fn semiconvergents<T: DivInt>(p: T, q: T) -> Semiconvergents<T> {
+    Semiconvergents { convergents: convergents(p, q), term: T::ZERO, j: T::ZERO }
+}
+
+/// Returns the fraction `(h, k)` closest to `p / q` with `0 < k <= max_den`,
+/// in lowest terms. Ties go to the smallest denominator, and to the lower
+/// fraction between two integers.
+///
+/// Returns `None` if `q` is zero, if `max_den` is not positive or if the
+/// fraction does not fit in the type.
+#[inline]
+pub fn best_approximation<T: DivInt>(p: T, q: T, max_den: T) -> Option<(T, T)> {
+    if q == T::ZERO || max_den <= T::ZERO {
+        return None;
+    }
+    // The remainders have the sign of `q` and the distances of the
+    // convergents `h0 / k0` and `h1 / k1` to `p / q` are `|r0| / (|q| * k0)`
+    // and `|r1| / (|q| * k1)`.
+    let (mut r0, mut r1) = (p, q);
+    let ((mut h0, mut k0), (mut h1, mut k1)) = ((T::ZERO, T::ONE), (T::ONE, T::ZERO));
+    loop {
+        // A term that overflows, from `MIN / -1`, is too large as well.
+        let step = r0.try_div_rem_floor(r1).ok();
+        let den = step.and_then(|(term, _)| term.checked_mul(k1)?.checked_add(k0));
+        let (Some((term, rem)), Some(den)) = (step, den.filter(|&den| den <= max_den)) else {
+            if k1 == T::ZERO {
+                // `p / q` is `MIN / -1`.
+                return None;
+            }
+            // The best approximation is either the previous convergent or
+            // the semiconvergent with the largest denominator, whose
+            // distance is `|r0 - j * r1| / (|q| * den)`.
+            let j = (max_den - k0) / k1;
+            let den = k0 + j * k1;
+            let ordering = cmp_fractions(r0 - j * r1, den, r1, k1);
+            let closer = if q < T::ZERO { Ordering::Greater } else { Ordering::Less };
+            if ordering != closer {
+                return Some((h1, k1));
+            }
+            return Some((h0.checked_add(j.checked_mul(h1)?)?, den));
+        };
+        let num = term.checked_mul(h1)?.checked_add(h0)?;
+        if rem == T::ZERO {
+            return Some((num, den));
+        }
+        ((h0, k0), (h1, k1)) = ((h1, k1), (num, den));
+        (r0, r1) = (r1, rem);
+    }
+}
+
+/// Compares `a / b` and `c / d` for positive `b` and `d`, with the
+/// continued fractions of both.
+fn cmp_fractions<T: DivInt>(mut a: T, mut b: T, mut c: T, mut d: T) -> Ordering {
+    loop {
+        let (q0, r0) = a.div_rem_floor(b);
+        let (q1, r1) = c.div_rem_floor(d);
+        if q0 != q1 {
+            return q0.cmp(&q1);
+        }
+        match (r0 == T::ZERO, r1 == T::ZERO) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            // `r0 / b` against `r1 / d` is `d / r1` against `b / r0`.
+            (false, false) => (a, b, c, d) = (d, r1, b, r0),
+        }
+    }
+}
+
+/// An iterator over the terms of a continued fraction, returned by
+/// [`terms`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Terms<T> {
+    p: T,
+    q: T,
+}
+
+impl<T: DivInt> Iterator for Terms<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.q == T::ZERO {
+            return None;
+        }
+        let Ok((a, r)) = self.p.try_div_rem_floor(self.q) else {
+            // `MIN / -1` overflows.
+            self.q = T::ZERO;
+            return None;
+        };
+        (self.p, self.q) = (self.q, r);
+        Some(a)
+    }
+}
+
+/// An iterator over the convergents of a continued fraction, returned by
+/// [`convergents`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Convergents<T> {
+    terms: Terms<T>,
+    prev: (T, T),
+    last: (T, T),
+}
+
+impl<T: DivInt> Iterator for Convergents<T> {
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<(T, T)> {
+        let a = self.terms.next()?;
+        let ((h0, k0), (h1, k1)) = (self.prev, self.last);
+        let next = |x0: T, x1: T| a.checked_mul(x1)?.checked_add(x0);
+        let (Some(h), Some(k)) = (next(h0, h1), next(k0, k1)) else {
+            self.terms.q = T::ZERO;
+            return None;
+        };
+        (self.prev, self.last) = (self.last, (h, k));
+        Some((h, k))
+    }
+}
+
+/// An iterator over the semiconvergents of a continued fraction, returned
+/// by [`semiconvergents`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Semiconvergents<T> {
+    convergents: Convergents<T>,
+    term: T,
+    j: T,
+}
+
+impl<T: DivInt> Iterator for Semiconvergents<T> {
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<(T, T)> {
+        if self.j == self.term {
+            let a = self.convergents.terms.clone().next()?;
+            if self.convergents.last.1 == T::ZERO {
+                // The integer part has no fractions before its convergent.
+                return self.convergents.next();
+            }
+            (self.term, self.j) = (a, T::ZERO);
+        }
+        self.j = self.j + T::ONE;
+        if self.j == self.term {
+            return self.convergents.next();
+        }
+        let ((h0, k0), (h1, k1)) = (self.convergents.prev, self.convergents.last);
+        let next = |x0: T, x1: T| self.j.checked_mul(x1)?.checked_add(x0);
+        let (Some(h), Some(k)) = (next(h0, h1), next(k0, k1)) else {
+            (self.convergents.terms.q, self.j) = (T::ZERO, self.term);
+            return None;
+        };
+        Some((h, k))
+    }
+}
@@ -0,0 +1,617 @@
+//! Decimal expansions of fractions.
+//!
+//! [`expand`] performs the long division of `a / b`. The expansion of a
+//! fraction is made of a sign, an integer part, fractional digits that do
+//! not repeat and a cycle of digits that repeats forever, which is empty if
+//! the expansion terminates. The digits are those of the magnitude of the
+//! fraction, so that `-1 / 7` is `-0.(142857)` whatever the signs of the
+//! operands.
+//!
+//! The digits are computed one at a time from the remainders, without
+//! allocating. The length of the non-repeating part is known in advance,
+//! the cycle ends when its first remainder comes back. [`Expansion`]
+//! implements `Display` with the cycle in parentheses.
+//!
+//! [`ScaledDecimal`] formats fixed-point numbers, integers scaled by a
+//! power of ten, with a given number of fractional digits and any rounding
+//! mode. [`parse_scaled_by`] and [`parse_scaled_exact`] parse them back.
+//!
+//! ```
+//! use divrem::decimal::expand;
+//! use divrem::rounding::{Floor, Round, Trunc};
+//!
+//! let expansion = expand(-1, 7).unwrap();
+//! assert!(expansion.is_negative());
+//! assert_eq!(expansion.integer(), 0);
+//! assert!(expansion.non_repeating().eq([]));
+//! assert!(expansion.repeating().eq([1, 4, 2, 8, 5, 7]));
+//! assert_eq!(expansion.to_string(), "-0.(142857)");
+//!
+//! assert_eq!(expand(7, 12).unwrap().to_string(), "0.58(3)");
+//! assert_eq!(expand(-22, 8).unwrap().to_string(), "-2.75");
+//!
+//! // The first digits, the last of which is rounded.
+//! let rounded = expand(2, 3).unwrap().rounded_by::<Round>(4);
+//! assert!(rounded.eq([6, 6, 6, 7]));
+//! let rounded = expand(-2, 3).unwrap().rounded_by::<Floor>(0);
+//! assert_eq!((rounded.is_negative(), rounded.integer()), (true, -1));
+//! let rounded = expand(-1, 3).unwrap().rounded_by::<Trunc>(2);
+//! assert!(rounded.is_negative() && rounded.eq([3, 3]));
+//! ```
+
+use core::convert::TryFrom;
+use core::iter::FusedIterator;
+use core::{cmp, fmt};
+
+use crate::multiple::cmp_magnitude;
+use crate::rounding::sealed::Fraction;
+use crate::{DivInt, Gcd, Rounding};
+
+/// Returns the decimal expansion of `a / b`, or `None` if `b` is zero or if
+/// the integer part, `a / b` truncated, does not fit in the type.
+#[inline]
+pub fn expand<T: DivInt>(a: T, b: T) -> Option<Expansion<T>> {
+    let (integer, rem) = a.checked_div_rem(b)?;
+    // The truncated remainder is smaller than `b` in magnitude, so it can
+    // be negated to get the sign of `b` and a floored long division.
+    let remainder =
+        if rem == T::ZERO || (rem < T::ZERO) == (b < T::ZERO) { rem } else { T::ZERO - rem };
+    let negative = a != T::ZERO && (a < T::ZERO) != (b < T::ZERO);
+    // The digits repeat once the powers of 2 and 5 of the reduced
+    // denominator are consumed. `gcd(remainder, b)` fits since the
+    // remainder is smaller than `b` in magnitude.
+    let pre_period = match remainder.checked_gcd(b) {
+        Some(gcd) if remainder != T::ZERO => {
+            let (two, five) = (T::ONE + T::ONE, five());
+            let twos = valuation(b, two) - valuation(gcd, two);
+            let fives = valuation(b, five) - valuation(gcd, five);
+            cmp::max(twos, fives)
+        }
+        _ => 0,
+    };
+    Some(Expansion {
+        negative,
+        dividend_negative: a < T::ZERO,
+        integer,
+        remainder,
+        divisor: b,
+        pre_period,
+    })
+}
+
+/// Returns the exponent of the prime `p` in `x`, which is not zero.
+#[inline]
+fn valuation<T: DivInt>(mut x: T, p: T) -> usize {
+    let mut count = 0;
+    while x % p == T::ZERO {
+        x = x / p;
+        count += 1;
+    }
+    count
+}
+
+/// Returns `5`.
+#[inline]
+fn five<T: DivInt>() -> T {
+    let two = T::ONE + T::ONE;
+    two + two + T::ONE
+}
+
+/// Returns `10^k`, or `None` if it does not fit in the type.
+#[inline]
+fn pow10<T: DivInt>(k: u32) -> Option<T> {
+    let ten = five::<T>() + five();
+    (0..k).try_fold(T::ONE, |power, _| power.checked_mul(ten))
+}
+
+/// Returns the fractional part of a quotient from its remainder `r` and
+/// the distance `rest` from the dividend to the next multiple.
+#[inline]
+fn fraction<T: DivInt>(r: T, rest: T) -> Fraction {
+    if r == T::ZERO {
+        Fraction::Zero
+    } else {
+        Fraction::from_cmp(cmp_magnitude(r, rest))
+    }
+}
+
+/// Returns the next digit of the long division of `r / b` and the next
+/// remainder, where `r` is zero or has the sign of `b` and is smaller in
+/// magnitude.
+///
+/// `10 * r` may overflow, so it is accumulated one `r` at a time: adding
+/// `r` to the accumulator `acc` reaches `b` when `|acc| >= |b - r|`, in
+/// which case `acc - (b - r)` is the remainder of the sum.
+#[inline]
+fn next_digit<T: DivInt>(r: T, b: T) -> (u8, T) {
+    let rest = b - r;
+    let (mut digit, mut acc) = (0, T::ZERO);
+    for _ in 0..10 {
+        let carry = if b > T::ZERO { acc >= rest } else { acc <= rest };
+        if carry {
+            digit += 1;
+            acc = acc - rest;
+        } else {
+            acc = acc + r;
+        }
+    }
+    (digit, acc)
+}
+
+/// The decimal expansion of a fraction, returned by [`expand`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Expansion<T> {
+    negative: bool,
+    dividend_negative: bool,
+    integer: T,
+    remainder: T,
+    divisor: T,
+    pre_period: usize,
+}
+
+impl<T: DivInt> Expansion<T> {
+    /// Returns whether the fraction is negative.
+    ///
+    /// The integer part is zero for fractions between -1 and 0, which are
+    /// still negative.
+    #[inline]
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the integer part, the quotient of the fraction truncated
+    /// towards zero.
+    #[inline]
+    #[must_use]
+    pub const fn integer(&self) -> T {
+        self.integer
+    }
+
+    /// Returns an iterator over the fractional digits before the cycle.
+    ///
+    /// If the expansion terminates, these are all the fractional digits and
+    /// the last of them is not zero.
+    #[inline]
+    #[must_use]
+    pub const fn non_repeating(&self) -> Digits<T> {
+        Digits {
+            remainder: self.remainder,
+            divisor: self.divisor,
+            len: self.pre_period,
+            stop: None,
+        }
+    }
+
+    /// Returns an iterator over the cycle of fractional digits that repeats
+    /// forever, which is empty if the expansion terminates.
+    ///
+    /// Its length is the order of 10 modulo the reduced denominator without
+    /// its factors 2 and 5, which may be as large as the denominator.
+    #[inline]
+    #[must_use]
+    pub fn repeating(&self) -> Digits<T> {
+        let mut digits = self.non_repeating();
+        digits.by_ref().for_each(drop);
+        let start = digits.remainder;
+        let len = if start == T::ZERO { 0 } else { usize::MAX };
+        Digits { remainder: start, divisor: self.divisor, len, stop: Some(start) }
+    }
+
+    /// Returns an iterator over the first `n` fractional digits, the last of
+    /// which is rounded with mode `M`.
+    ///
+    /// Rounding may carry into the previous digits and the integer part,
+    /// which are available from [`Rounded::integer`]. Trailing zeros are
+    /// included, so there are always `n` digits.
+    #[inline]
+    pub fn rounded_by<M: Rounding>(&self, n: usize) -> Rounded<T> {
+        let digits =
+            Digits { remainder: self.remainder, divisor: self.divisor, len: n, stop: None };
+        // The index of the last digit other than 9, which a carry stops at.
+        let (mut last_digit, mut last_not_nine, mut nonzero) = (None, None, false);
+        let mut scan = digits.clone();
+        for (i, digit) in scan.by_ref().enumerate() {
+            last_digit = Some(digit);
+            if digit != 9 {
+                last_not_nine = Some(i);
+            }
+            nonzero |= digit != 0;
+        }
+        let remainder = scan.remainder;
+        let fraction = fraction(remainder, self.divisor - remainder);
+        let odd = last_digit
+            .map_or_else(|| self.integer % (T::ONE + T::ONE) != T::ZERO, |digit| digit % 2 == 1);
+        let mut integer = self.integer;
+        let (increment, zeros) =
+            if !M::round_up(self.negative, self.dividend_negative, odd, fraction) {
+                (usize::MAX, usize::MAX)
+            } else if let Some(i) = last_not_nine {
+                nonzero = true;
+                (i, i + 1)
+            } else {
+                // There is a fraction to round, so `|b| >= 2` and the integer
+                // part is far from the limits of the type.
+                integer = if self.negative { integer - T::ONE } else { integer + T::ONE };
+                (usize::MAX, 0)
+            };
+        let negative = self.negative && (nonzero || integer != T::ZERO);
+        Rounded { negative, integer, digits, index: 0, increment, zeros }
+    }
+}
+
+impl<T: DivInt + fmt::Display> fmt::Display for Expansion<T> {
+    /// Writes the expansion with the cycle in parentheses, `-0.58(3)`.
+    ///
+    /// The whole cycle is written, which may take as many digits as the
+    /// denominator.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative && self.integer == T::ZERO {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", self.integer)?;
+        if self.remainder == T::ZERO {
+            return Ok(());
+        }
+        f.write_str(".")?;
+        for digit in self.non_repeating() {
+            write!(f, "{digit}")?;
+        }
+        let mut cycle = self.repeating().peekable();
+        if cycle.peek().is_some() {
+            f.write_str("(")?;
+            for digit in cycle {
+                write!(f, "{digit}")?;
+            }
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+/// A `Display` adapter writing `value / 10^scale` with `digits` fractional
+/// digits, rounded with mode `M`.
+///
+/// The number is written from the decimal digits of `value`, without
+/// floating point arithmetic or allocation, for any scale and number of
+/// digits: missing digits are zeros and extra digits are rounded away. A
+/// number that rounds to zero is written without a sign.
+///
+/// ```
+/// use divrem::decimal::ScaledDecimal;
+/// use divrem::rounding::{Floor, HalfEven};
+///
+/// // Millidegrees with one fractional digit, ties to even.
+/// let celsius = ScaledDecimal { value: 21_450, scale: 3, digits: 1, mode: HalfEven };
+/// assert_eq!(celsius.to_string(), "21.4");
+/// let celsius = ScaledDecimal { value: -21_550, ..celsius };
+/// assert_eq!(celsius.to_string(), "-21.6");
+///
+/// // Cents.
+/// let cents = ScaledDecimal { value: -1_999_i64, scale: 2, digits: 0, mode: Floor };
+/// assert_eq!(cents.to_string(), "-20");
+/// let cents = ScaledDecimal { value: 5, scale: 2, digits: 4, mode: Floor };
+/// assert_eq!(cents.to_string(), "0.0500");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ScaledDecimal<T, M> {
+    /// The scaled integer.
+    pub value: T,
+    /// The power of ten `value` is divided by.
+    pub scale: u32,
+    /// The number of fractional digits to write.
+    pub digits: u32,
+    /// The rounding mode marker, from the [`rounding`](crate::rounding)
+    /// module.
+    pub mode: M,
+}
+
+impl<T: DivInt, M: Rounding> fmt::Display for ScaledDecimal<T, M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (value, zero) = (self.value, T::ZERO);
+        let negative = value < zero;
+        // Drop the digits that are not written.
+        let (mut kept, fraction) = match self.scale.saturating_sub(self.digits) {
+            0 => (value, Fraction::Zero),
+            dropped => div_pow10(value, dropped),
+        };
+        if M::round_up(negative, negative, kept % (T::ONE + T::ONE) != zero, fraction) {
+            // `kept` is at most a tenth of `value` in magnitude.
+            kept = if negative { kept - T::ONE } else { kept + T::ONE };
+        }
+        if kept < zero {
+            f.write_str("-")?;
+        }
+        write_digits(f, kept, cmp::min(self.scale, self.digits))?;
+        if self.digits > self.scale {
+            if self.scale == 0 {
+                f.write_str(".")?;
+            }
+            for _ in self.scale..self.digits {
+                f.write_str("0")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns `value / 10^k` truncated and the fractional part of the
+/// quotient, for `k > 0`.
+#[inline]
+fn div_pow10<T: DivInt>(value: T, k: u32) -> (T, Fraction) {
+    if let Some(divisor) = pow10::<T>(k) {
+        let (q, r) = (value / divisor, value % divisor);
+        let rest = if r < T::ZERO { r + divisor } else { divisor - r };
+        return (q, fraction(r, rest));
+    }
+    // `|value| < 10^k`, compared with the half `5 * 10^(k - 1)` if it fits.
+    let half = pow10::<T>(k - 1).and_then(|power| power.checked_mul(five()));
+    let ordering = half.map_or(cmp::Ordering::Less, |half| cmp_magnitude(value, half));
+    let fraction = if value == T::ZERO { Fraction::Zero } else { Fraction::from_cmp(ordering) };
+    (T::ZERO, fraction)
+}
+
+/// Writes the magnitude of `n` with a decimal point before the last `point`
+/// digits, padded with zeros so that there is a digit before the point.
+#[inline]
+fn write_digits<T: DivInt>(f: &mut fmt::Formatter<'_>, n: T, point: u32) -> fmt::Result {
+    let ten = five::<T>() + five();
+    // `point + 1` overflows `u32` if `point` is `u32::MAX`.
+    let power = |i: u64| u32::try_from(i).ok().and_then(pow10::<T>);
+    let mut len = u64::from(point) + 1;
+    while power(len).is_some_and(|power| cmp_magnitude(n, power) != cmp::Ordering::Less) {
+        len += 1;
+    }
+    for i in (0..len).rev() {
+        // The digit has the sign of `n`, and is zero above `n`.
+        let mut digit = power(i).map_or(T::ZERO, |power| n / power % ten);
+        let mut value = 0;
+        while digit != T::ZERO {
+            digit = if digit < T::ZERO { digit + T::ONE } else { digit - T::ONE };
+            value += 1;
+        }
+        write!(f, "{value}")?;
+        if i == u64::from(point) && point > 0 {
+            f.write_str(".")?;
+        }
+    }
+    Ok(())
+}
+
+/// Parses a decimal number into an integer scaled by `10^scale`, rounded
+/// with mode `M`.
+///
+/// The number is an optional sign followed by digits with an optional
+/// decimal point, `-12.3456`, `+7`, `1.` or `.5`, without exponent, spaces
+/// or separators. It is parsed without floating point arithmetic: the
+/// digits beyond `scale` fractional digits are only compared with a half to
+/// round the last one, so there may be any number of them.
+///
+/// # Errors
+///
+/// Returns [`ParseDecimalError::InvalidSyntax`] if the string is not a
+/// decimal number and [`ParseDecimalError::Overflow`] if the rounded scaled
+/// integer does not fit in the type, for instance if it is negative and the
+/// type is unsigned.
+///
+/// ```
+/// use divrem::decimal::{parse_scaled_by, ParseDecimalError};
+/// use divrem::rounding::{Floor, HalfEven, Trunc};
+///
+/// assert_eq!(parse_scaled_by::<Floor, i64>("-12.3456", 2), Ok(-1235));
+/// assert_eq!(parse_scaled_by::<Trunc, i64>("-12.3456", 2), Ok(-1234));
+/// assert_eq!(parse_scaled_by::<HalfEven, i64>("0.125", 2), Ok(12));
+/// assert_eq!(parse_scaled_by::<HalfEven, i32>("21.4", 3), Ok(21_400));
+/// assert_eq!(parse_scaled_by::<Floor, u8>("2.56", 2), Err(ParseDecimalError::Overflow));
+/// assert_eq!(parse_scaled_by::<Floor, u8>("1e3", 0), Err(ParseDecimalError::InvalidSyntax));
+/// ```
+#[inline]
+pub fn parse_scaled_by<M: Rounding, T: DivInt>(
+    s: &str,
+    scale: u32,
+) -> Result<T, ParseDecimalError> {
+    let (value, negative, fraction) = parse_truncated::<T>(s, scale)?;
+    let odd = value % (T::ONE + T::ONE) != T::ZERO;
+    if !M::round_up(negative, negative, odd, fraction) {
+        return Ok(value);
+    }
+    let rounded = if negative { value.checked_sub(T::ONE) } else { value.checked_add(T::ONE) };
+    rounded.ok_or(ParseDecimalError::Overflow)
+}
+
+/// Parses a decimal number into an integer scaled by `10^scale`, which must
+/// be exact.
+///
+/// The syntax is the one of [`parse_scaled_by`]. Trailing zeros beyond
+/// `scale` fractional digits are accepted.
+///
+/// # Errors
+///
+/// Returns [`ParseDecimalError::Inexact`] if the number has non-zero digits
+/// beyond `scale` fractional digits, and the errors of [`parse_scaled_by`].
+///
+/// ```
+/// use divrem::decimal::{parse_scaled_exact, ParseDecimalError};
+///
+/// assert_eq!(parse_scaled_exact::<i64>("-12.3400", 2), Ok(-1234));
+/// assert_eq!(parse_scaled_exact::<i64>("-12.3456", 2), Err(ParseDecimalError::Inexact));
+/// ```
+#[inline]
+pub fn parse_scaled_exact<T: DivInt>(s: &str, scale: u32) -> Result<T, ParseDecimalError> {
+    match parse_truncated(s, scale)? {
+        (value, _, Fraction::Zero) => Ok(value),
+        _ => Err(ParseDecimalError::Inexact),
+    }
+}
+
+/// Parses a decimal number into an integer scaled by `10^scale` and
+/// truncated, its sign and the fractional part of the digits beyond
+/// `scale`.
+#[inline]
+fn parse_truncated<T: DivInt>(
+    s: &str,
+    scale: u32,
+) -> Result<(T, bool, Fraction), ParseDecimalError> {
+    let (negative, unsigned) = match s.as_bytes() {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        bytes => (false, bytes),
+    };
+    let point = unsigned.iter().position(|&byte| byte == b'.').unwrap_or(unsigned.len());
+    let (integer, fractional) = unsigned.split_at(point);
+    let fractional = fractional.get(1..).unwrap_or_default();
+    let valid = integer.iter().chain(fractional).all(u8::is_ascii_digit);
+    if !valid || integer.len() + fractional.len() == 0 {
+        return Err(ParseDecimalError::InvalidSyntax);
+    }
+    // The value is accumulated with its sign, so that `MIN` fits.
+    let digit_values: [T; 10] = core::array::from_fn(|i| (0..i).fold(T::ZERO, |x, _| x + T::ONE));
+    let ten = five::<T>() + five();
+    let push = |value: T, byte: u8| {
+        let digit = digit_values[usize::from(byte - b'0')];
+        let value = value.checked_mul(ten)?;
+        if negative {
+            value.checked_sub(digit)
+        } else {
+            value.checked_add(digit)
+        }
+    };
+    let scale = usize::try_from(scale).unwrap_or(usize::MAX);
+    let (kept, dropped) = fractional.split_at(cmp::min(scale, fractional.len()));
+    let overflow = ParseDecimalError::Overflow;
+    let mut value = integer
+        .iter()
+        .chain(kept)
+        .try_fold(T::ZERO, |value, &byte| push(value, byte))
+        .ok_or(overflow)?;
+    // Zero stays zero, without looping over a large scale.
+    if value != T::ZERO {
+        for _ in kept.len()..scale {
+            value = push(value, b'0').ok_or(overflow)?;
+        }
+    }
+    let fraction = match dropped {
+        [] => Fraction::Zero,
+        [first, rest @ ..] => {
+            let rest_zero = rest.iter().all(|&byte| byte == b'0');
+            match first.cmp(&b'5') {
+                cmp::Ordering::Less if *first == b'0' && rest_zero => Fraction::Zero,
+                cmp::Ordering::Equal if rest_zero => Fraction::Half,
+                cmp::Ordering::Less => Fraction::BelowHalf,
+                _ => Fraction::AboveHalf,
+            }
+        }
+    };
+    Ok((value, negative, fraction))
+}
+
+/// The error type of [`parse_scaled_by`] and [`parse_scaled_exact`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseDecimalError {
+    /// The string is not a decimal number.
+    InvalidSyntax,
+    /// The scaled integer does not fit in the type.
+    Overflow,
+    /// The number has non-zero digits beyond the scale, which an exact
+    /// parse does not round.
+    Inexact,
+}
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDecimalError::InvalidSyntax => f.write_str("invalid decimal number"),
+            ParseDecimalError::Overflow => f.write_str("scaled integer overflow"),
+            ParseDecimalError::Inexact => f.write_str("digits beyond the scale"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDecimalError {}
+
+/// An iterator over fractional digits of a decimal expansion, returned by
+/// [`Expansion::non_repeating`] and [`Expansion::repeating`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Digits<T> {
+    remainder: T,
+    divisor: T,
+    len: usize,
+    stop: Option<T>,
+}
+
+impl<T: DivInt> Iterator for Digits<T> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.len == 0 {
+            return None;
+        }
+        let (digit, remainder) = next_digit(self.remainder, self.divisor);
+        self.remainder = remainder;
+        self.len = if self.stop == Some(remainder) { 0 } else { self.len - 1 };
+        Some(digit)
+    }
+}
+
+impl<T: DivInt> FusedIterator for Digits<T> {}
+
+/// An iterator over the first fractional digits of a decimal expansion,
+/// the last of which is rounded, returned by [`Expansion::rounded_by`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rounded<T> {
+    negative: bool,
+    integer: T,
+    digits: Digits<T>,
+    index: usize,
+    increment: usize,
+    zeros: usize,
+}
+
+impl<T: DivInt> Rounded<T> {
+    /// Returns whether the rounded fraction is negative, which it is not if
+    /// it rounds to zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the integer part of the rounded fraction, truncated towards
+    /// zero.
+    #[inline]
+    #[must_use]
+    pub const fn integer(&self) -> T {
+        self.integer
+    }
+}
+
+impl<T: DivInt> Iterator for Rounded<T> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        let digit = self.digits.next()?;
+        let i = self.index;
+        self.index += 1;
+        Some(if i >= self.zeros {
+            0
+        } else if i == self.increment {
+            digit + 1
+        } else {
+            digit
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.digits.len, Some(self.digits.len))
+    }
+}
+
+impl<T: DivInt> ExactSizeIterator for Rounded<T> {}
+
+impl<T: DivInt> FusedIterator for Rounded<T> {}
@@ -0,0 +1,238 @@
+//! Linear Diophantine equations.
+//!
+//! [`solve`] finds the integer solutions of `a * x + b * y = c`. They form
+//! a family `(x + x_step * t, y + y_step * t)` parameterised by an integer
+//! `t`, see [`GeneralSolution`]. The range of `t` for which the solutions
+//! lie in given ranges is computed with floored and ceiled division, which
+//! round in the right direction whatever the signs of the coefficients.
+//!
+//! ```
+//! use divrem::diophantine::solve;
+//!
+//! // 6 * x + 9 * y = 21.
+//! let solution = solve(6, 9, 21).unwrap();
+//! assert_eq!((solution.x(), solution.y()), (2, 1));
+//! assert_eq!((solution.x_step(), solution.y_step()), (3, -2));
+//! assert_eq!(solution.checked_at(-1), Some((-1, 3)));
+//!
+//! // The solutions with `0 <= x <= 10` and `-5 <= y <= 5`.
+//! assert_eq!(solution.count_in(0..=10, -5..=5), Some(3));
+//! let solutions = solution.solutions_in(0..=10, -5..=5).unwrap();
+//! assert!(solutions.eq([(2, 1), (5, -1), (8, -3)]));
+//! ```
+
+use core::iter::FusedIterator;
+use core::ops::{Neg, RangeInclusive};
+
+use crate::congruence::solve_linear_congruence;
+use crate::rounding::{Ceil, Floor};
+use crate::{DivBy, DivInt, DivRemFloor, Rounding};
+
+/// Returns the general solution of `a * x + b * y = c`.
+///
+/// Returns `None` if there is no solution, that is if `gcd(a, b)` does not
+/// divide `c`, if `a` and `b` are both zero, or if computing the general
+/// solution overflows. The solutions may be negative whatever the signs of
+/// the coefficients, so this function requires a signed type.
+#[inline]
+pub fn solve<T>(a: T, b: T, c: T) -> Option<GeneralSolution<T>>
+where T: DivInt + Neg<Output = T> {
+    if b == T::ZERO {
+        // `x` is fixed and `y` is free.
+        if a == T::ZERO || c.checked_rem(a)? != T::ZERO {
+            return None;
+        }
+        let x = c.checked_div(a)?;
+        return Some(GeneralSolution { x, y: T::ZERO, x_step: T::ZERO, y_step: T::ONE });
+    }
+    // The values of `x` are the solutions of `a * x ≡ c (mod |b|)`, the
+    // smallest of which is non-negative.
+    let modulus = if b < T::ZERO { T::ZERO.checked_sub(b)? } else { b };
+    let solutions = solve_linear_congruence(a, c, modulus)?;
+    let (residue, x_step) = (solutions.residue(), solutions.modulus());
+    let y = c.checked_sub(a.checked_mul(residue)?)? / b;
+    let a_step = a / (modulus / x_step);
+    let y_step = if b < T::ZERO { a_step } else { T::ZERO.checked_sub(a_step)? };
+    Some(GeneralSolution { x: residue, y, x_step, y_step })
+}
+
+/// The general solution of a linear Diophantine equation, returned by
+/// [`solve`].
+///
+/// The solutions of `a * x + b * y = c` are the pairs
+/// `(x + x_step * t, y + y_step * t)` for every integer `t`. If `b` is not
+/// zero, `x_step` is `|b| / gcd(a, b)` and `x` is the smallest non-negative
+/// value of all the solutions. Otherwise `x` is fixed, `x_step` is zero and
+/// `y` takes every value, starting from zero with a `y_step` of one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GeneralSolution<T> {
+    x: T,
+    y: T,
+    x_step: T,
+    y_step: T,
+}
+
+impl<T: DivInt> GeneralSolution<T> {
+    /// Returns the value of `x` for `t == 0`.
+    #[inline]
+    #[must_use]
+    pub const fn x(&self) -> T {
+        self.x
+    }
+
+    /// Returns the value of `y` for `t == 0`.
+    #[inline]
+    #[must_use]
+    pub const fn y(&self) -> T {
+        self.y
+    }
+
+    /// Returns the difference between the values of `x` for consecutive
+    /// values of `t`, which is never negative.
+    #[inline]
+    #[must_use]
+    pub const fn x_step(&self) -> T {
+        self.x_step
+    }
+
+    /// Returns the difference between the values of `y` for consecutive
+    /// values of `t`.
+    #[inline]
+    #[must_use]
+    pub const fn y_step(&self) -> T {
+        self.y_step
+    }
+
+    /// Returns the solution for the parameter `t`, or `None` if it does not
+    /// fit in the type.
+    #[inline]
+    pub fn checked_at(&self, t: T) -> Option<(T, T)> {
+        let x = self.x.checked_add(self.x_step.checked_mul(t)?)?;
+        let y = self.y.checked_add(self.y_step.checked_mul(t)?)?;
+        Some((x, y))
+    }
+
+    /// Returns the range of the parameters `t` of the solutions with `x` in
+    /// `x_range` and `y` in `y_range`, which is empty if there are none.
+    #[inline]
+    pub fn parameters_in(
+        &self,
+        x_range: RangeInclusive<T>,
+        y_range: RangeInclusive<T>,
+    ) -> RangeInclusive<T> {
+        let empty = T::ONE..=T::ZERO;
+        let (mut lo, mut hi) = (T::MIN, T::MAX);
+        for (base, step, range) in [(self.x, self.x_step, x_range), (self.y, self.y_step, y_range)]
+        {
+            let (min, max) = (*range.start(), *range.end());
+            if step == T::ZERO {
+                if !range.contains(&base) {
+                    return empty;
+                }
+                continue;
+            }
+            if min > max {
+                return empty;
+            }
+            // `min <= base + step * t <= max`, the bounds swap when dividing
+            // by a negative step.
+            // A bound out of the range of the type is either no constraint
+            // or unreachable, depending on its sign.
+            let (first, second) = if step > T::ZERO { (min, max) } else { (max, min) };
+            let positive = |bound: T| (bound > base) == (step > T::ZERO);
+            match offset_quotient::<Ceil, _>(first, base, step) {
+                Some(bound) => lo = lo.max(bound),
+                None if positive(first) => return empty,
+                None => {}
+            }
+            match offset_quotient::<Floor, _>(second, base, step) {
+                Some(bound) => hi = hi.min(bound),
+                None if positive(second) => {}
+                None => return empty,
+            }
+        }
+        if lo > hi {
+            empty
+        } else {
+            lo..=hi
+        }
+    }
+
+    /// Returns the number of solutions with `x` in `x_range` and `y` in
+    /// `y_range`, or `None` if it does not fit in the type.
+    #[inline]
+    pub fn count_in(&self, x_range: RangeInclusive<T>, y_range: RangeInclusive<T>) -> Option<T> {
+        let parameters = self.parameters_in(x_range, y_range);
+        if parameters.is_empty() {
+            return Some(T::ZERO);
+        }
+        parameters.end().checked_sub(*parameters.start())?.checked_add(T::ONE)
+    }
+
+    /// Returns an iterator over the solutions with `x` in `x_range` and `y`
+    /// in `y_range`, in increasing order of `t`, or `None` if computing the
+    /// first solution overflows.
+    #[inline]
+    pub fn solutions_in(
+        &self,
+        x_range: RangeInclusive<T>,
+        y_range: RangeInclusive<T>,
+    ) -> Option<Solutions<T>> {
+        let parameters = self.parameters_in(x_range, y_range);
+        let (t, end) = (*parameters.start(), *parameters.end());
+        let next = if parameters.is_empty() { None } else { Some(self.checked_at(t)?) };
+        Some(Solutions { next, step: (self.x_step, self.y_step), t, end })
+    }
+}
+
+/// An iterator over the solutions of a linear Diophantine equation in given
+/// ranges, returned by [`GeneralSolution::solutions_in`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Solutions<T> {
+    next: Option<(T, T)>,
+    step: (T, T),
+    t: T,
+    end: T,
+}
+
+impl<T: DivInt> Iterator for Solutions<T> {
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<(T, T)> {
+        let (x, y) = self.next?;
+        // The next solution is in the ranges, the sums do not overflow.
+        self.next = if self.t < self.end {
+            self.t = self.t + T::ONE;
+            Some((x + self.step.0, y + self.step.1))
+        } else {
+            None
+        };
+        Some((x, y))
+    }
+}
+
+impl<T: DivInt> FusedIterator for Solutions<T> {}
+
+/// Returns `(bound - base) / step` rounded with `M`, or `None` if it does
+/// not fit in the type. `step` is not zero.
+#[inline]
+fn offset_quotient<M, T>(bound: T, base: T, step: T) -> Option<T>
+where
+    M: Rounding,
+    T: DivInt + DivBy<M, Output = T>,
+{
+    if T::IS_SIGNED && step == T::ZERO - T::ONE {
+        return base.checked_sub(bound);
+    }
+    if let Some(diff) = bound.checked_sub(base) {
+        return Some(diff.div_by(step));
+    }
+    // `bound - base` overflows, split both values by `step` instead. The
+    // remainders have the sign of `step` so their difference divided by
+    // `step` is in `(-1, 1)` and moves the quotient by at most one.
+    let (high, low) = bound.div_rem_floor(step);
+    let (base_high, base_low) = base.div_rem_floor(step);
+    let adjust = (low - base_low).div_by(step);
+    high.checked_sub(base_high)?.checked_add(adjust)
+}
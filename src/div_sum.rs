@@ -0,0 +1,185 @@
+use core::convert::TryFrom;
+
+use crate::{wide, DivError, TryDivRem};
+
+/// Sums of the quotients of an arithmetic progression.
+///
+/// `T::floor_sum(n, m, a, b)` is the sum of `(a * i + b).div_floor(m)` for
+/// `i` in `0..n`, the number of lattice points under a line. It is computed
+/// in `O(log m)` steps with the algorithm of the `AtCoder` library, instead of
+/// `n` divisions. `ceil_sum` and `euclid_sum` sum the ceiled and euclidean
+/// quotients. `a`, `b` and `m` may be negative, the quotients follow the
+/// semantics of [`DivFloor`](crate::DivFloor), [`DivCeil`](crate::DivCeil)
+/// and [`DivEuclid`](crate::DivEuclid).
+///
+/// The methods return `None` if `n` is negative, if `m` is zero or on
+/// overflow. The intermediate values are computed with 128 bits or more, so
+/// only the sum can overflow, except for `i128` where the sums of the
+/// quotients of `a * i` and `b` by `m` must fit too.
+///
+/// This trait is implemented for the primitive integer types.
+///
+/// ```
+/// use divrem::DivSum;
+///
+/// // ⌊1 / 3⌋ + ⌊3 / 3⌋ + ⌊5 / 3⌋ + ⌊7 / 3⌋ = 0 + 1 + 1 + 2
+/// assert_eq!(i32::floor_sum(4, 3, 2, 1), Some(4));
+/// // ⌊-1 / 3⌋ + ⌊-3 / 3⌋ + ⌊-5 / 3⌋ + ⌊-7 / 3⌋ = -1 - 1 - 2 - 3
+/// assert_eq!(i32::floor_sum(4, 3, -2, -1), Some(-7));
+/// assert_eq!(i32::ceil_sum(4, 3, -2, -1), Some(-4));
+/// assert_eq!(u64::floor_sum(1 << 40, 1 << 40, 1, 0), Some(0));
+/// ```
+pub trait DivSum: Sized {
+    /// Returns the sum of `(a * i + b).div_floor(m)` for `i` in `0..n`.
+    fn floor_sum(n: Self, m: Self, a: Self, b: Self) -> Option<Self>;
+
+    /// Returns the sum of `(a * i + b).div_ceil(m)` for `i` in `0..n`.
+    fn ceil_sum(n: Self, m: Self, a: Self, b: Self) -> Option<Self>;
+
+    /// Returns the sum of `(a * i + b).div_euclid(m)` for `i` in `0..n`.
+    fn euclid_sum(n: Self, m: Self, a: Self, b: Self) -> Option<Self>;
+}
+
+/// Returns `n * (n - 1) / 2`, or `None` if it does not fit.
+#[inline]
+const fn triangle(n: u128) -> Option<u128> {
+    if n % 2 == 0 {
+        (n / 2).checked_mul(n.saturating_sub(1))
+    } else {
+        n.checked_mul(n / 2)
+    }
+}
+
+/// Returns the sum of `⌊(a * i + b) / m⌋` for `i` in `0..n`, or `None` if it
+/// does not fit.
+///
+/// The terms are never negative, so neither are the partial sums and they
+/// overflow only if the sum does. `a * n + b` is computed with 256 bits.
+fn floor_sum_unsigned(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> Option<u128> {
+    let mut sum = 0_u128;
+    loop {
+        if a >= m {
+            sum = sum.checked_add(triangle(n)?.checked_mul(a / m)?)?;
+            a %= m;
+        }
+        if b >= m {
+            sum = sum.checked_add(n.checked_mul(b / m)?)?;
+            b %= m;
+        }
+        let (high, low) = wide::mul_u128(a, n);
+        let (low, carry) = low.overflowing_add(b);
+        let high = high + u128::from(carry);
+        if high == 0 && low < m {
+            return Some(sum);
+        }
+        // `a * n + b < m * (n + 1)`, so the quotient fits.
+        let (quotient, remainder) = wide::div_rem_u256(high, low, m);
+        (n, m, a, b) = (quotient, a, m, remainder);
+    }
+}
+
+/// Returns the sum of `(a * i + b) / m` for `i` in `0..n`, where `div_rem`
+/// is the division with remainder of a rounding mode.
+///
+/// With `a = qa * m + ra` and `b = qb * m + rb`, the quotient is
+/// `qa * i + qb + (ra * i + rb) / m`. The remainders have the same sign in
+/// every mode, so the last quotient is rounded towards zero and sums to a
+/// floored sum of magnitudes.
+#[inline]
+fn sum_i128(
+    n: i128,
+    m: i128,
+    a: i128,
+    b: i128,
+    div_rem: fn(i128, i128) -> Result<(i128, i128), DivError>,
+) -> Option<i128> {
+    if n < 0 {
+        return None;
+    }
+    // `qa * n * (n - 1) / 2 + qb * n`.
+    let linear = |qa: i128, qb: i128| {
+        let triangle =
+            if qa == 0 { 0 } else { i128::try_from(triangle(n.unsigned_abs())?).ok()? };
+        qa.checked_mul(triangle)?.checked_add(qb.checked_mul(n)?)
+    };
+    if m == -1 {
+        // Every mode divides exactly, `MIN / -1` overflows.
+        return linear(a, b)?.checked_neg();
+    }
+    let ((qa, ra), (qb, rb)) = (div_rem(a, m).ok()?, div_rem(b, m).ok()?);
+    let (n_abs, m_abs) = (n.unsigned_abs(), m.unsigned_abs());
+    let rest = floor_sum_unsigned(n_abs, m_abs, ra.unsigned_abs(), rb.unsigned_abs())?;
+    let rest = i128::try_from(rest).ok()?;
+    let rest = if (ra < 0 || rb < 0) == (m < 0) { rest } else { -rest };
+    linear(qa, qb)?.checked_add(rest)
+}
+
+/// Returns the sum of `(a * i + b) / m` for `i` in `0..n` with `i128`
+/// intermediate values.
+#[inline]
+fn sum_wide<T>(
+    n: T,
+    m: T,
+    a: T,
+    b: T,
+    div_rem: fn(i128, i128) -> Result<(i128, i128), DivError>,
+) -> Option<T>
+where
+    T: TryFrom<i128>,
+    i128: TryFrom<T>,
+{
+    let wide = |x: T| i128::try_from(x).ok();
+    let sum = sum_i128(wide(n)?, wide(m)?, wide(a)?, wide(b)?, div_rem)?;
+    T::try_from(sum).ok()
+}
+
+macro_rules! impl_div_sum {
+    ($($t:ty),*) => {$(
+        impl DivSum for $t {
+            #[inline]
+            fn floor_sum(n: Self, m: Self, a: Self, b: Self) -> Option<Self> {
+                sum_wide(n, m, a, b, TryDivRem::try_div_rem_floor)
+            }
+
+            #[inline]
+            fn ceil_sum(n: Self, m: Self, a: Self, b: Self) -> Option<Self> {
+                sum_wide(n, m, a, b, TryDivRem::try_div_rem_ceil)
+            }
+
+            #[inline]
+            fn euclid_sum(n: Self, m: Self, a: Self, b: Self) -> Option<Self> {
+                sum_wide(n, m, a, b, TryDivRem::try_div_rem_euclid)
+            }
+        }
+    )*};
+}
+
+impl_div_sum!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl DivSum for u128 {
+    #[inline]
+    fn floor_sum(n: Self, m: Self, a: Self, b: Self) -> Option<Self> {
+        if m == 0 {
+            return None;
+        }
+        floor_sum_unsigned(n, m, a, b)
+    }
+
+    #[inline]
+    fn ceil_sum(n: Self, m: Self, a: Self, b: Self) -> Option<Self> {
+        if m == 0 {
+            return None;
+        }
+        // `⌈(a * i + b) / m⌉ = ⌊(a * i + b + m - 1) / m⌋`. With `b = qb * m + rb`,
+        // `b + m - 1` is split into `(qb + 1) * m + rb - 1` if `rb > 0` so that
+        // it does not overflow, and the terms of both sums are not negative.
+        let (qb, rb) = (b / m, b % m);
+        let (qb, rb) = if rb == 0 { (qb, m - 1) } else { (qb + 1, rb - 1) };
+        floor_sum_unsigned(n, m, a, rb)?.checked_add(n.checked_mul(qb)?)
+    }
+
+    #[inline]
+    fn euclid_sum(n: Self, m: Self, a: Self, b: Self) -> Option<Self> {
+        Self::floor_sum(n, m, a, b)
+    }
+}
@@ -0,0 +1,22 @@
+use core::fmt;
+
+/// The error type of the `try_*` division methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DivError {
+    /// The divisor is zero.
+    DivisionByZero,
+    /// The quotient does not fit in the type, as in `MIN / -1`.
+    Overflow,
+}
+
+impl fmt::Display for DivError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DivError::DivisionByZero => f.write_str("division by zero"),
+            DivError::Overflow => f.write_str("quotient overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DivError {}
@@ -0,0 +1,166 @@
+use core::ops::Neg;
+
+use crate::{DivInt, DivRemEuclid, RemEuclid};
+
+/// Greatest common divisor, least common multiple and modular inverse.
+///
+/// The algorithms are Euclid's, built on euclidean division. The greatest
+/// common divisor and the least common multiple are never negative, and
+/// `gcd(0, 0)` is `0`. They do not fit in signed types only when the result
+/// is `-MIN`, for instance `gcd(MIN, 0)`, in which case the checked methods
+/// return `None` and the others panic.
+///
+/// This trait is implemented for all types implementing [`DivInt`].
+pub trait Gcd: DivInt {
+    /// Returns the greatest common divisor of `self` and `other`, or `None`
+    /// if it does not fit in the type.
+    #[inline]
+    fn checked_gcd(self, other: Self) -> Option<Self> {
+        let (mut a, mut b) = (self, other);
+        while b != Self::ZERO {
+            let r = if is_minus_one(b) { Self::ZERO } else { a.rem_euclid(b) };
+            (a, b) = (b, r);
+        }
+        // Only one of the arguments can be negative here.
+        magnitude(a)
+    }
+
+    /// Returns `(g, x, y)` where `g` is the greatest common divisor of
+    /// `self` and `other` and `self * x + other * y == g`, or `None` if one
+    /// of them does not fit in the type.
+    ///
+    /// The Bézout coefficients are the ones of Euclid's algorithm: if
+    /// neither argument divides the other, `|x| <= |other / g|` and
+    /// `|y| <= |self / g|`. They may be negative, so this method requires
+    /// a signed type.
+    #[inline]
+    fn checked_extended_gcd(self, other: Self) -> Option<(Self, Self, Self)>
+    where Self: Neg<Output = Self> {
+        // Invariant: `self * s + other * t == r` for both rows.
+        let (mut r0, mut s0, mut t0) = (self, Self::ONE, Self::ZERO);
+        let (mut r1, mut s1, mut t1) = (other, Self::ZERO, Self::ONE);
+        while r1 != Self::ZERO {
+            let (q, r) = if is_minus_one(r1) {
+                // `MIN / -1` overflows, the quotient is not needed.
+                (Self::ZERO, Self::ZERO)
+            } else {
+                r0.div_rem_euclid(r1)
+            };
+            if r == Self::ZERO {
+                // Stop before computing the next coefficients, `±other / g`
+                // and `∓self / g`, which may not fit.
+                (r0, s0, t0) = (r1, s1, t1);
+                break;
+            }
+            let s = s0.checked_sub(q.checked_mul(s1)?)?;
+            let t = t0.checked_sub(q.checked_mul(t1)?)?;
+            (r0, s0, t0) = (r1, s1, t1);
+            (r1, s1, t1) = (r, s, t);
+        }
+        if r0 < Self::ZERO {
+            let negate = |x: Self| Self::ZERO.checked_sub(x);
+            Some((negate(r0)?, negate(s0)?, negate(t0)?))
+        } else {
+            Some((r0, s0, t0))
+        }
+    }
+
+    /// Returns the least common multiple of `self` and `other`, or `None`
+    /// if it does not fit in the type.
+    #[inline]
+    fn checked_lcm(self, other: Self) -> Option<Self> {
+        if self == Self::ZERO || other == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+        let g = self.checked_gcd(other)?;
+        magnitude((self / g).checked_mul(other)?)
+    }
+
+    /// Returns the inverse of `self` modulo `modulus`, in `[0, modulus)`.
+    ///
+    /// Returns `None` if `modulus` is not positive or if `self` is not
+    /// coprime with `modulus`. Every value is its own inverse modulo `1`,
+    /// which returns `0`.
+    #[inline]
+    fn mod_inverse(self, modulus: Self) -> Option<Self> {
+        if modulus <= Self::ZERO {
+            return None;
+        }
+        // Euclid's algorithm on `modulus` and `self`, where the coefficients
+        // of `self` alternate in sign and only their magnitudes are stored.
+        // The magnitudes are at most `modulus`, they never overflow.
+        let (mut r0, mut r1) = (modulus, self.rem_euclid(modulus));
+        let (mut u0, mut u1) = (Self::ZERO, Self::ONE);
+        let mut negative = true;
+        while r1 != Self::ZERO {
+            let (q, r) = r0.div_rem_euclid(r1);
+            (r0, r1) = (r1, r);
+            (u0, u1) = (u1, u0 + q * u1);
+            negative = !negative;
+        }
+        if r0 != Self::ONE {
+            None
+        } else if negative && u0 != Self::ZERO {
+            Some(modulus - u0)
+        } else {
+            Some(u0)
+        }
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in the type.
+    #[inline]
+    #[must_use]
+    fn gcd(self, other: Self) -> Self {
+        self.checked_gcd(other)
+            .unwrap_or_else(|| panic!("attempt to compute the gcd with overflow"))
+    }
+
+    /// Returns `(g, x, y)` where `g` is the greatest common divisor of
+    /// `self` and `other` and `self * x + other * y == g`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the results do not fit in the type.
+    #[inline]
+    #[must_use]
+    fn extended_gcd(self, other: Self) -> (Self, Self, Self)
+    where Self: Neg<Output = Self> {
+        self.checked_extended_gcd(other)
+            .unwrap_or_else(|| panic!("attempt to compute the gcd with overflow"))
+    }
+
+    /// Returns the least common multiple of `self` and `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in the type.
+    #[inline]
+    #[must_use]
+    fn lcm(self, other: Self) -> Self {
+        self.checked_lcm(other)
+            .unwrap_or_else(|| panic!("attempt to compute the lcm with overflow"))
+    }
+}
+
+impl<T: DivInt> Gcd for T {}
+
+/// Returns whether `x` is `-1`, which divides every integer but overflows
+/// when dividing `MIN`.
+#[inline]
+fn is_minus_one<T: DivInt>(x: T) -> bool {
+    T::IS_SIGNED && x == T::ZERO - T::ONE
+}
+
+/// Returns `|x|`, or `None` if it does not fit in the type.
+#[inline]
+fn magnitude<T: DivInt>(x: T) -> Option<T> {
+    if x < T::ZERO {
+        T::ZERO.checked_sub(x)
+    } else {
+        Some(x)
+    }
+}
@@ -0,0 +1,361 @@
+use core::convert::TryFrom;
+use core::num::{Saturating, Wrapping};
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+/// Integer types supported by the division traits.
+///
+/// Every division variant of this crate is implemented for all types
+/// implementing this trait, so that integer types outside of `core`
+/// (wide integers, ranged integers, …) only need to implement `DivInt` to
+/// get floored, ceiled and euclidean division. The ceiled remainder is only
+/// implemented for types that also implement `Neg`.
+///
+/// The `/` and `%` operators must implement truncated division, as the
+/// primitive integer operators do. The division variants build on the
+/// checked methods, only deferring to the operators on division by zero or
+/// overflow, so that implementations can provide faster paths: `i128` and
+/// `u128` use 64-bit division when both operands fit in 64 bits.
+///
+/// For `Saturating` types, the division variants saturate like the `/`
+/// operator does: `MIN / -1` is `MAX` in every mode, with a zero remainder.
+/// Their checked division and remainder methods return these saturated
+/// results and only fail on division by zero. Dividing by zero panics, as
+/// for the other types.
+pub trait DivInt:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// The value `0`.
+    const ZERO: Self;
+
+    /// The value `1`.
+    const ONE: Self;
+
+    /// The smallest value of the type.
+    const MIN: Self;
+
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// Whether the type can represent negative values.
+    const IS_SIGNED: bool;
+
+    /// Checked addition. Returns `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Checked subtraction. Returns `None` on overflow.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Checked multiplication. Returns `None` on overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Checked truncated division.
+    /// Returns `None` if `other == 0` or the quotient overflows.
+    fn checked_div(self, other: Self) -> Option<Self>;
+
+    /// Checked truncated remainder.
+    /// Returns `None` if `other == 0` or the quotient overflows.
+    fn checked_rem(self, other: Self) -> Option<Self>;
+
+    /// Checked truncated division and remainder.
+    /// Returns `None` if `other == 0` or the quotient overflows.
+    ///
+    /// The default implementation is built on `checked_div` and
+    /// `checked_rem`. Implementations for types where division is slow
+    /// should compute both with a single division.
+    #[inline]
+    fn checked_div_rem(self, other: Self) -> Option<(Self, Self)> {
+        Some((self.checked_div(other)?, self.checked_rem(other)?))
+    }
+
+    /// Checked ceiled division.
+    /// Returns `None` if `other == 0` or the quotient overflows.
+    ///
+    /// Implementations should delegate to an inherent `div_ceil` method when
+    /// the type has one. The default implementation is built on
+    /// `checked_div_rem`.
+    #[inline]
+    fn checked_div_ceil(self, other: Self) -> Option<Self> {
+        let (q, r) = self.checked_div_rem(other)?;
+        if (r > Self::ZERO && other > Self::ZERO) || (r < Self::ZERO && other < Self::ZERO) {
+            q.checked_add(Self::ONE)
+        } else {
+            Some(q)
+        }
+    }
+
+    /// Checked euclidean division.
+    /// Returns `None` if `other == 0` or the quotient overflows.
+    ///
+    /// Implementations should delegate to an inherent `checked_div_euclid`
+    /// method when the type has one. The default implementation is built on
+    /// `checked_div_rem`.
+    #[inline]
+    fn checked_div_euclid(self, other: Self) -> Option<Self> {
+        let (q, r) = self.checked_div_rem(other)?;
+        if r < Self::ZERO {
+            if other > Self::ZERO {
+                q.checked_sub(Self::ONE)
+            } else {
+                q.checked_add(Self::ONE)
+            }
+        } else {
+            Some(q)
+        }
+    }
+
+    /// Checked euclidean remainder.
+    /// Returns `None` if `other == 0` or the quotient overflows.
+    ///
+    /// Implementations should delegate to an inherent `checked_rem_euclid`
+    /// method when the type has one. The default implementation is built on
+    /// `checked_rem`.
+    #[inline]
+    fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+        let r = self.checked_rem(other)?;
+        if r < Self::ZERO {
+            if other > Self::ZERO {
+                r.checked_add(other)
+            } else {
+                r.checked_sub(other)
+            }
+        } else {
+            Some(r)
+        }
+    }
+}
+
+// The division variants go through the checked methods, which have fast
+// paths for some types, and only defer to the operators on division by
+// zero or overflow.
+
+/// Truncated division `x / y`.
+#[inline]
+pub fn div<T: DivInt>(x: T, y: T) -> T {
+    x.checked_div(y).unwrap_or_else(|| x / y)
+}
+
+/// Truncated remainder `x % y`.
+///
+/// The `%` operator of `Saturating` types panics on `MIN % -1`, which the
+/// checked remainder saturates.
+#[inline]
+pub fn rem<T: DivInt>(x: T, y: T) -> T {
+    x.checked_rem(y).unwrap_or_else(|| x % y)
+}
+
+/// Truncated division and remainder `(x / y, x % y)`.
+#[inline]
+pub fn div_rem<T: DivInt>(x: T, y: T) -> (T, T) {
+    x.checked_div_rem(y).unwrap_or_else(|| (x / y, rem(x, y)))
+}
+
+/// Maps the result of a checked division of `Saturating` values, replacing
+/// an overflow (`MIN / -1`) with `saturated`.
+#[inline]
+fn saturate<T: DivInt>(
+    result: Option<T>,
+    other: T,
+    saturated: Saturating<T>,
+) -> Option<Saturating<T>> {
+    match result {
+        Some(result) => Some(Saturating(result)),
+        None if other == T::ZERO => None,
+        None => Some(saturated),
+    }
+}
+
+/// Returns early with the result of `$method` on the operands narrowed to
+/// `$narrow`, if they fit.
+///
+/// 128-bit division is a slow library call while 64-bit division is a
+/// hardware instruction, and 128-bit values often fit in 64 bits.
+macro_rules! narrow {
+    ($x:ident.$method:ident($y:ident) as $narrow:ty $(=> |$q:ident, $r:ident|)?) => {
+        if let (Ok(x), Ok(y)) = (<$narrow>::try_from($x), <$narrow>::try_from($y)) {
+            // `MIN / -1` overflows in 64 bits but not in 128 bits.
+            if let Some(result) = x.$method(y) {
+                return Some(narrow!(@widen result $(, $q, $r)?));
+            }
+        }
+    };
+    (@widen $result:ident) => {
+        $result.into()
+    };
+    (@widen $result:ident, $q:ident, $r:ident) => {{
+        let ($q, $r) = $result;
+        ($q.into(), $r.into())
+    }};
+}
+
+macro_rules! impl_div_int {
+    ($t:ty, $signed:expr, $($narrow:ty)?, { $($div_ceil:tt)* }) => {
+        impl DivInt for $t {
+            const IS_SIGNED: bool = $signed;
+            const MAX: Self = <$t>::MAX;
+            const MIN: Self = <$t>::MIN;
+            const ONE: Self = 1;
+            const ZERO: Self = 0;
+
+            #[inline]
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            #[inline]
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            #[inline]
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            #[inline]
+            fn checked_div(self, other: Self) -> Option<Self> {
+                $(narrow!(self.checked_div(other) as $narrow);)?
+                <$t>::checked_div(self, other)
+            }
+
+            #[inline]
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                $(narrow!(self.checked_rem(other) as $narrow);)?
+                <$t>::checked_rem(self, other)
+            }
+
+            $(
+            #[inline]
+            fn checked_div_rem(self, other: Self) -> Option<(Self, Self)> {
+                narrow!(self.checked_div_rem(other) as $narrow => |q, r|);
+                // A single division, the product cannot overflow.
+                let q = <$t>::checked_div(self, other)?;
+                Some((q, self - q * other))
+            }
+            )?
+
+            #[inline]
+            fn checked_div_euclid(self, other: Self) -> Option<Self> {
+                $(narrow!(self.checked_div_euclid(other) as $narrow);)?
+                <$t>::checked_div_euclid(self, other)
+            }
+
+            #[inline]
+            fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+                $(narrow!(self.checked_rem_euclid(other) as $narrow);)?
+                <$t>::checked_rem_euclid(self, other)
+            }
+
+            $($div_ceil)*
+        }
+
+        impl DivInt for Wrapping<$t> {
+            const IS_SIGNED: bool = $signed;
+            const MAX: Self = Wrapping(<$t>::MAX);
+            const MIN: Self = Wrapping(<$t>::MIN);
+            const ONE: Self = Wrapping(1);
+            const ZERO: Self = Wrapping(0);
+
+            #[inline]
+            fn checked_add(self, other: Self) -> Option<Self> {
+                self.0.checked_add(other.0).map(Wrapping)
+            }
+
+            #[inline]
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                self.0.checked_sub(other.0).map(Wrapping)
+            }
+
+            #[inline]
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                self.0.checked_mul(other.0).map(Wrapping)
+            }
+
+            #[inline]
+            fn checked_div(self, other: Self) -> Option<Self> {
+                self.0.checked_div(other.0).map(Wrapping)
+            }
+
+            #[inline]
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                self.0.checked_rem(other.0).map(Wrapping)
+            }
+
+            #[inline]
+            fn checked_div_euclid(self, other: Self) -> Option<Self> {
+                self.0.checked_div_euclid(other.0).map(Wrapping)
+            }
+
+            #[inline]
+            fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+                self.0.checked_rem_euclid(other.0).map(Wrapping)
+            }
+        }
+
+        impl DivInt for Saturating<$t> {
+            const IS_SIGNED: bool = $signed;
+            const MAX: Self = Saturating(<$t>::MAX);
+            const MIN: Self = Saturating(<$t>::MIN);
+            const ONE: Self = Saturating(1);
+            const ZERO: Self = Saturating(0);
+
+            #[inline]
+            fn checked_add(self, other: Self) -> Option<Self> {
+                self.0.checked_add(other.0).map(Saturating)
+            }
+
+            #[inline]
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                self.0.checked_sub(other.0).map(Saturating)
+            }
+
+            #[inline]
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                self.0.checked_mul(other.0).map(Saturating)
+            }
+
+            #[inline]
+            fn checked_div(self, other: Self) -> Option<Self> {
+                saturate(self.0.checked_div(other.0), other.0, Self::MAX)
+            }
+
+            #[inline]
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                saturate(self.0.checked_rem(other.0), other.0, Self::ZERO)
+            }
+
+            #[inline]
+            fn checked_div_euclid(self, other: Self) -> Option<Self> {
+                saturate(self.0.checked_div_euclid(other.0), other.0, Self::MAX)
+            }
+
+            #[inline]
+            fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+                saturate(self.0.checked_rem_euclid(other.0), other.0, Self::ZERO)
+            }
+        }
+    };
+    (signed: $($t:ty $(as $narrow:ty)?),*) => {$(
+        impl_div_int!($t, true, $($narrow)?, {});
+    )*};
+    (unsigned: $($t:ty $(as $narrow:ty)?),*) => {$(
+        impl_div_int!($t, false, $($narrow)?, {
+            #[inline]
+            fn checked_div_ceil(self, other: Self) -> Option<Self> {
+                if other == 0 {
+                    return None;
+                }
+                $(narrow!(self.checked_div_ceil(other) as $narrow);)?
+                Some(<$t>::div_ceil(self, other))
+            }
+        });
+    )*};
+}
+
+impl_div_int!(signed: i8, i16, i32, i64, i128 as i64, isize);
+impl_div_int!(unsigned: u8, u16, u32, u64, u128 as u64, usize);
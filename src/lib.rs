@@ -16,6 +16,71 @@
 //!
 //! A `DivRem` variant of the truncated division is also provided for
 //! convenience since it does not exist in the standard library.
+//!
+//! The `DivBy`, `RemBy` and `DivRemBy` traits take the rounding mode as a
+//! type parameter (see the [`rounding`] module) so that generic code can be
+//! parameterised by rounding mode at compile time.
+//!
+//! All the variants are implemented for the types implementing the
+//! [`DivInt`] trait, which includes the primitive integer types and their
+//! `Wrapping` and `Saturating` counterparts.
+//!
+//! The primitive integer types have inherent `div_euclid`, `rem_euclid` and
+//! `div_ceil` methods which shadow the trait methods of the same name in
+//! method-call syntax. The implementations of this crate delegate to them
+//! so that both always agree, and the [`DivRemExt`] trait provides the
+//! variants under prefixed names (`floor_div`, `euclid_rem`, …) that can't be
+//! shadowed.
+//!
+//! The [`TryDivRem`] trait provides checked variants returning a
+//! [`DivError`] on division by zero or overflow. With the `std` feature,
+//! `DivError` and [`ParseDecimalError`](decimal::ParseDecimalError)
+//! implement `std::error::Error`. The [`Checked`] wrapper chains divisions
+//! and becomes poisoned on division by zero or overflow.
+//!
+//! The [`MulDiv`] trait computes `a * b / c` with a double-width
+//! intermediate product and any rounding mode.
+//!
+//! The [`DivRemWide`] trait divides a double-word number by a single word.
+//!
+//! The [`DivPow2`] trait divides by a power of two with shifts and masks.
+//!
+//! The [`RoundToMultiple`] trait rounds integers to a multiple of another
+//! with any rounding mode.
+//!
+//! The [`Roots`] and [`ILog`] traits compute exact integer roots and
+//! logarithms with any rounding mode.
+//!
+//! The [`DivSum`] trait sums the floored, ceiled or euclidean quotients of
+//! an arithmetic progression in logarithmic time.
+//!
+//! The [`Gcd`] trait computes greatest common divisors, Bézout
+//! coefficients, least common multiples and modular inverses.
+//!
+//! The [`Mod`] and [`DynMod`] types are integers modulo a compile-time or
+//! run-time modulus, normalised with the euclidean remainder.
+//!
+//! The [`congruence`] module solves systems of congruences with the Chinese
+//! remainder theorem, and linear congruences.
+//!
+//! The [`continued_fraction`] module expands fractions into continued
+//! fractions and finds their best rational approximations.
+//!
+//! The [`decimal`] module expands fractions into decimal digits, with the
+//! repeating cycle or a rounded last digit, and formats and parses scaled
+//! integers as fixed-point numbers.
+//!
+//! The [`diophantine`] module solves linear Diophantine equations.
+//!
+//! The [`radix`] module decomposes integers into mixed-radix digits in any
+//! rounding mode, and composes them back.
+//!
+//! The [`limbs`] module divides integers wider than `u128`, stored as
+//! slices of limbs.
+//!
+//! The [`prelude`] module re-exports every trait, and the
+//! [`DivisionVariants`] trait bundles them into a single bound for generic
+//! code.
 
 #![warn(missing_docs)]
 #![warn(rust_2018_idioms)]
@@ -26,6 +91,9 @@
 #![allow(clippy::use_self)]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::ops::{Div, Rem};
 
 /// Truncated division and remainder.
@@ -128,9 +196,207 @@ pub trait DivRemCeil<RHS = Self>: DivRem<RHS> {
     fn div_rem_ceil(self, other: RHS) -> <Self as DivRem<RHS>>::Output;
 }
 
+/// Division with a statically selected rounding mode.
+///
+/// `M` is one of the marker types from the [`rounding`] module.
+pub trait DivBy<M: Rounding, RHS = Self>: Div<RHS> {
+    /// Performs the division operation with rounding mode `M`.
+    fn div_by(self, other: RHS) -> <Self as Div<RHS>>::Output;
+}
+
+/// Division remainder with a statically selected rounding mode.
+///
+/// `M` is one of the marker types from the [`rounding`] module.
+pub trait RemBy<M: Rounding, RHS = Self>: Rem<RHS> {
+    /// Returns the remainder of the division operation with rounding mode `M`.
+    fn rem_by(self, other: RHS) -> <Self as Rem<RHS>>::Output;
+}
+
+/// Division and remainder with a statically selected rounding mode.
+///
+/// `M` is one of the marker types from the [`rounding`] module.
+pub trait DivRemBy<M: Rounding, RHS = Self>: DivRem<RHS> {
+    /// Performs the division operation with remainder with rounding mode `M`.
+    fn div_rem_by(self, other: RHS) -> <Self as DivRem<RHS>>::Output;
+}
+
+/// Division variants under names that do not collide with inherent methods.
+///
+/// The primitive integer types have inherent `div_euclid` and `rem_euclid`
+/// methods, and unsigned ones an inherent `div_ceil` method. Inherent methods
+/// take precedence over trait methods in method-call syntax, so `x.div_euclid(y)`
+/// on a primitive does not call `DivEuclid::div_euclid`. The methods of this
+/// trait have prefixed names and always dispatch to the traits of this crate.
+///
+/// This trait is implemented for all types.
+pub trait DivRemExt: Sized {
+    /// Performs the `/` operation. See [`Div`].
+    #[inline]
+    fn trunc_div<RHS>(self, other: RHS) -> <Self as Div<RHS>>::Output
+    where Self: Div<RHS> {
+        Div::div(self, other)
+    }
+
+    /// Performs the `%` operation. See [`Rem`].
+    #[inline]
+    fn trunc_rem<RHS>(self, other: RHS) -> <Self as Rem<RHS>>::Output
+    where Self: Rem<RHS> {
+        Rem::rem(self, other)
+    }
+
+    /// Performs the `/` and `%` operations. See [`DivRem`].
+    #[inline]
+    fn trunc_div_rem<RHS>(self, other: RHS) -> <Self as DivRem<RHS>>::Output
+    where Self: DivRem<RHS> {
+        DivRem::div_rem(self, other)
+    }
+
+    /// Performs the floored division operation. See [`DivFloor`].
+    #[inline]
+    fn floor_div<RHS>(self, other: RHS) -> <Self as Div<RHS>>::Output
+    where Self: DivFloor<RHS> {
+        DivFloor::div_floor(self, other)
+    }
+
+    /// Returns the remainder of the floored division operation.
+    /// See [`RemFloor`].
+    #[inline]
+    fn floor_rem<RHS>(self, other: RHS) -> <Self as Rem<RHS>>::Output
+    where Self: RemFloor<RHS> {
+        RemFloor::rem_floor(self, other)
+    }
+
+    /// Performs the floored division operation with remainder.
+    /// See [`DivRemFloor`].
+    #[inline]
+    fn floor_div_rem<RHS>(self, other: RHS) -> <Self as DivRem<RHS>>::Output
+    where Self: DivRemFloor<RHS> {
+        DivRemFloor::div_rem_floor(self, other)
+    }
+
+    /// Performs the ceiled division operation. See [`DivCeil`].
+    #[inline]
+    fn ceil_div<RHS>(self, other: RHS) -> <Self as Div<RHS>>::Output
+    where Self: DivCeil<RHS> {
+        DivCeil::div_ceil(self, other)
+    }
+
+    /// Returns the remainder of the ceiled division operation.
+    /// See [`RemCeil`].
+    #[inline]
+    fn ceil_rem<RHS>(self, other: RHS) -> <Self as Rem<RHS>>::Output
+    where Self: RemCeil<RHS> {
+        RemCeil::rem_ceil(self, other)
+    }
+
+    /// Performs the ceiled division operation with remainder.
+    /// See [`DivRemCeil`].
+    #[inline]
+    fn ceil_div_rem<RHS>(self, other: RHS) -> <Self as DivRem<RHS>>::Output
+    where Self: DivRemCeil<RHS> {
+        DivRemCeil::div_rem_ceil(self, other)
+    }
+
+    /// Performs the euclidean division operation. See [`DivEuclid`].
+    #[inline]
+    fn euclid_div<RHS>(self, other: RHS) -> <Self as Div<RHS>>::Output
+    where Self: DivEuclid<RHS> {
+        DivEuclid::div_euclid(self, other)
+    }
+
+    /// Returns the remainder of the euclidean division operation.
+    /// See [`RemEuclid`].
+    #[inline]
+    fn euclid_rem<RHS>(self, other: RHS) -> <Self as Rem<RHS>>::Output
+    where Self: RemEuclid<RHS> {
+        RemEuclid::rem_euclid(self, other)
+    }
+
+    /// Performs the euclidean division operation with remainder.
+    /// See [`DivRemEuclid`].
+    #[inline]
+    fn euclid_div_rem<RHS>(self, other: RHS) -> <Self as DivRem<RHS>>::Output
+    where Self: DivRemEuclid<RHS> {
+        DivRemEuclid::div_rem_euclid(self, other)
+    }
+}
+
+impl<T> DivRemExt for T {}
+
+/// All the division variants of this crate, with `Self` as output.
+///
+/// This trait is implemented for any type implementing the truncated,
+/// floored, ceiled and euclidean `Div`, `Rem` and `DivRem` traits with
+/// `Self` as the quotient and remainder type. Generic code can use it as a
+/// single bound instead of listing every trait.
+///
+/// The ceiled remainder traits `RemCeil` and `DivRemCeil` are not included
+/// since the ceiled modulus of unsigned types is negative or zero and they
+/// are not implemented for unsigned primitives.
+pub trait DivisionVariants:
+    Sized
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + DivRem<Output = (Self, Self)>
+    + DivFloor
+    + RemFloor
+    + DivRemFloor
+    + DivCeil
+    + DivEuclid
+    + RemEuclid
+    + DivRemEuclid
+{
+}
+
+impl<T> DivisionVariants for T where T: Sized
+        + Div<Output = T>
+        + Rem<Output = T>
+        + DivRem<Output = (T, T)>
+        + DivFloor
+        + RemFloor
+        + DivRemFloor
+        + DivCeil
+        + DivEuclid
+        + RemEuclid
+        + DivRemEuclid
+{
+}
+
+pub use checked::{Checked, TryDivRem};
+pub use div_sum::DivSum;
+pub use error::DivError;
+pub use gcd::Gcd;
+pub use int::DivInt;
+pub use modular::{DynMod, Mod};
+pub use mul_div::MulDiv;
+pub use multiple::RoundToMultiple;
+pub use pow2::DivPow2;
+pub use roots::{ILog, Roots};
+pub use rounding::Rounding;
+pub use wide::{DivRemWide, DivRemWideCeil};
+
 #[macro_use]
 mod macros;
 mod ceil;
+mod checked;
+pub mod congruence;
+pub mod continued_fraction;
+pub mod decimal;
+pub mod diophantine;
+mod div_sum;
+mod error;
 mod euclid;
 mod floor;
+mod gcd;
+mod int;
+pub mod limbs;
+mod modular;
+mod mul_div;
+mod multiple;
+mod pow2;
+pub mod prelude;
+pub mod radix;
+mod roots;
+pub mod rounding;
 mod trunc;
+mod wide;
@@ -0,0 +1,490 @@
+//! Division of multi-limb integers.
+//!
+//! A multi-limb integer is a little-endian slice of limbs: the first limb is
+//! the least significant one. The limbs are `u32` or `u64` values, see
+//! [`Limb`]. Signed integers are in two's complement, the most significant
+//! bit of the last limb being the sign bit. An empty slice is zero.
+//!
+//! [`div_rem`] divides by a single limb, [`div_rem_long`] by another
+//! multi-limb integer. Both work in place without allocating.
+
+use core::cmp::Ordering;
+use core::ops::{BitOr, Neg, Not};
+
+use crate::rounding::sealed::Fraction;
+use crate::rounding::{Ceil, Euclid, Floor, Trunc};
+use crate::{DivInt, DivRemWide, Rounding};
+
+mod sealed {
+    use core::ops::{Shl, Shr};
+
+    pub trait Sealed: Sized + Shl<u32, Output = Self> + Shr<u32, Output = Self> {
+        const BITS: u32;
+
+        fn leading_zeros(self) -> u32;
+
+        fn overflowing_add(self, other: Self) -> (Self, bool);
+
+        fn overflowing_sub(self, other: Self) -> (Self, bool);
+
+        /// Returns the double-width product as `(high, low)` words.
+        fn mul_wide(self, other: Self) -> (Self, Self);
+    }
+}
+
+/// A limb of a multi-limb integer.
+///
+/// This trait is sealed and implemented by `u32` and `u64`.
+pub trait Limb:
+    DivInt + DivRemWide<Low = Self> + Not<Output = Self> + BitOr<Output = Self> + sealed::Sealed
+{
+    /// The signed integer type of the same width.
+    type Signed: DivInt + Neg<Output = Self::Signed>;
+
+    /// Reinterprets a signed value as a limb, in two's complement.
+    fn from_signed(x: Self::Signed) -> Self;
+
+    /// Reinterprets the limb as a signed value, in two's complement.
+    fn to_signed(self) -> Self::Signed;
+}
+
+macro_rules! impl_limb {
+    ($($t:ty, $s:ty, $w:ty);*) => {$(
+        impl sealed::Sealed for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                <$t>::leading_zeros(self)
+            }
+
+            #[inline]
+            fn overflowing_add(self, other: Self) -> (Self, bool) {
+                <$t>::overflowing_add(self, other)
+            }
+
+            #[inline]
+            fn overflowing_sub(self, other: Self) -> (Self, bool) {
+                <$t>::overflowing_sub(self, other)
+            }
+
+            #[inline]
+            #[allow(clippy::cast_possible_truncation)] // Splitting into words.
+            fn mul_wide(self, other: Self) -> (Self, Self) {
+                let p = <$w>::from(self) * <$w>::from(other);
+                ((p >> <$t>::BITS) as $t, p as $t)
+            }
+        }
+
+        impl Limb for $t {
+            type Signed = $s;
+
+            #[inline]
+            #[allow(clippy::cast_sign_loss)] // Two's complement reinterpretation.
+            fn from_signed(x: $s) -> Self {
+                x as $t
+            }
+
+            #[inline]
+            #[allow(clippy::cast_possible_wrap)] // Two's complement reinterpretation.
+            fn to_signed(self) -> $s {
+                self as $s
+            }
+        }
+    )*};
+}
+
+impl_limb!(u32, i32, u64; u64, i64, u128);
+
+/// Divides the unsigned integer `n` by `d` in place and returns the remainder.
+///
+/// # Panics
+///
+/// Panics if `d` is zero.
+pub fn div_rem<L: Limb>(n: &mut [L], d: L) -> L {
+    assert!(d != L::ZERO, "attempt to divide by zero");
+    let mut r = L::ZERO;
+    for limb in n.iter_mut().rev() {
+        // The quotient always fits in a limb since `r < d`.
+        let (q, next) = L::div_rem_wide(r, *limb, d).unwrap_or((L::ZERO, L::ZERO));
+        *limb = q;
+        r = next;
+    }
+    r
+}
+
+/// Divides the signed integer `n` by `d` in place with rounding mode `M`
+/// and returns the remainder.
+///
+/// # Panics
+///
+/// Panics if `d` is zero or if the quotient overflows, that is if `n` is the
+/// smallest value of its width and `d` is `-1`.
+pub fn div_rem_signed_by<M: Rounding, L: Limb>(n: &mut [L], d: L::Signed) -> L::Signed {
+    let d_negative = d < L::Signed::ZERO;
+    let d = magnitude(L::from_signed(d), d_negative);
+    assert!(d != L::ZERO, "attempt to divide by zero");
+    let n_negative = is_negative(n);
+    if n_negative {
+        negate(n);
+        // The smallest value is its own opposite.
+        assert!(!(is_negative(n) && d == L::ONE && d_negative), "attempt to divide with overflow");
+    }
+    let mut r = div_rem(n, d);
+    let negative = n_negative != d_negative;
+    let odd = n.first().is_some_and(|&limb| limb % (L::ONE + L::ONE) != L::ZERO);
+    let r_negative = if M::round_up(negative, n_negative, odd, Fraction::new(r, d)) {
+        increment(n);
+        r = d - r;
+        !n_negative
+    } else {
+        n_negative
+    };
+    if negative {
+        negate(n);
+    }
+    magnitude(r, r_negative).to_signed()
+}
+
+/// Divides the signed integer `n` by `d` in place and returns the remainder.
+///
+/// See [`DivRem`](crate::DivRem) and [`div_rem_signed_by`].
+pub fn div_rem_signed<L: Limb>(n: &mut [L], d: L::Signed) -> L::Signed {
+    div_rem_signed_by::<Trunc, L>(n, d)
+}
+
+/// Divides the signed integer `n` by `d` in place with floored division
+/// and returns the remainder.
+///
+/// See [`DivRemFloor`](crate::DivRemFloor) and [`div_rem_signed_by`].
+pub fn div_rem_signed_floor<L: Limb>(n: &mut [L], d: L::Signed) -> L::Signed {
+    div_rem_signed_by::<Floor, L>(n, d)
+}
+
+/// Divides the signed integer `n` by `d` in place with ceiled division
+/// and returns the remainder.
+///
+/// See [`DivRemCeil`](crate::DivRemCeil) and [`div_rem_signed_by`].
+pub fn div_rem_signed_ceil<L: Limb>(n: &mut [L], d: L::Signed) -> L::Signed {
+    div_rem_signed_by::<Ceil, L>(n, d)
+}
+
+/// Divides the signed integer `n` by `d` in place with euclidean division
+/// and returns the remainder.
+///
+/// See [`DivRemEuclid`](crate::DivRemEuclid) and [`div_rem_signed_by`].
+pub fn div_rem_signed_euclid<L: Limb>(n: &mut [L], d: L::Signed) -> L::Signed {
+    div_rem_signed_by::<Euclid, L>(n, d)
+}
+
+/// Divides the unsigned integer `n` by the unsigned integer `d`.
+///
+/// The quotient is stored in `q` and `n` is replaced by the remainder.
+///
+/// This is algorithm D from Knuth's The Art of Computer Programming,
+/// section 4.3.1.
+///
+/// # Panics
+///
+/// Panics if `d` is zero or if `q` has fewer limbs than `n`.
+pub fn div_rem_long<L: Limb>(n: &mut [L], d: &[L], q: &mut [L]) {
+    long_div(n, &Divisor::unsigned(d), q);
+}
+
+/// Divides the signed integer `n` by the signed integer `d` with rounding
+/// mode `M`.
+///
+/// The quotient is stored in `q` and `n` is replaced by the remainder.
+///
+/// # Panics
+///
+/// Panics if `d` is zero, if `q` has fewer limbs than `n`, if `n` has fewer
+/// limbs than `d` or if the quotient overflows, that is if `n` is the
+/// smallest value of its width, `d` is `-1` and `q` has as many limbs as `n`.
+pub fn div_rem_long_signed_by<M: Rounding, L: Limb>(n: &mut [L], d: &[L], q: &mut [L]) {
+    assert!(n.len() >= d.len(), "remainder buffer too short");
+    let d = Divisor::signed(d);
+    let n_negative = is_negative(n);
+    if n_negative {
+        negate(n);
+        // The smallest value is its own opposite.
+        let overflow = is_negative(n) && d.is_minus_one() && q.len() == n.len();
+        assert!(!overflow, "attempt to divide with overflow");
+    }
+    long_div(n, &d, q);
+    let negative = n_negative != d.negative;
+    let odd = q.first().is_some_and(|&limb| limb % (L::ONE + L::ONE) != L::ZERO);
+    let r_negative = if M::round_up(negative, n_negative, odd, d.fraction(n)) {
+        increment(q);
+        d.sub_from(n);
+        !n_negative
+    } else {
+        n_negative
+    };
+    if negative {
+        negate(q);
+    }
+    if r_negative {
+        negate(n);
+    }
+}
+
+/// Divides the signed integer `n` by the signed integer `d`.
+///
+/// See [`DivRem`](crate::DivRem) and [`div_rem_long_signed_by`].
+pub fn div_rem_long_signed<L: Limb>(n: &mut [L], d: &[L], q: &mut [L]) {
+    div_rem_long_signed_by::<Trunc, L>(n, d, q);
+}
+
+/// Divides the signed integer `n` by the signed integer `d` with floored
+/// division.
+///
+/// See [`DivRemFloor`](crate::DivRemFloor) and [`div_rem_long_signed_by`].
+pub fn div_rem_long_signed_floor<L: Limb>(n: &mut [L], d: &[L], q: &mut [L]) {
+    div_rem_long_signed_by::<Floor, L>(n, d, q);
+}
+
+/// Divides the signed integer `n` by the signed integer `d` with ceiled
+/// division.
+///
+/// See [`DivRemCeil`](crate::DivRemCeil) and [`div_rem_long_signed_by`].
+pub fn div_rem_long_signed_ceil<L: Limb>(n: &mut [L], d: &[L], q: &mut [L]) {
+    div_rem_long_signed_by::<Ceil, L>(n, d, q);
+}
+
+/// Divides the signed integer `n` by the signed integer `d` with euclidean
+/// division.
+///
+/// See [`DivRemEuclid`](crate::DivRemEuclid) and [`div_rem_long_signed_by`].
+pub fn div_rem_long_signed_euclid<L: Limb>(n: &mut [L], d: &[L], q: &mut [L]) {
+    div_rem_long_signed_by::<Euclid, L>(n, d, q);
+}
+
+/// The magnitude of a divisor, read limb by limb.
+struct Divisor<'a, L> {
+    limbs: &'a [L],
+    negative: bool,
+    /// The index of the lowest non-zero limb.
+    lowest: usize,
+    /// The number of significant limbs of the magnitude.
+    len: usize,
+}
+
+impl<'a, L: Limb> Divisor<'a, L> {
+    fn unsigned(limbs: &'a [L]) -> Self {
+        Self { limbs, negative: false, lowest: 0, len: significant_len(limbs) }
+    }
+
+    fn signed(limbs: &'a [L]) -> Self {
+        if !is_negative(limbs) {
+            return Self::unsigned(limbs);
+        }
+        let lowest = limbs.iter().position(|&limb| limb != L::ZERO).unwrap_or(0);
+        let mut d = Self { limbs, negative: true, lowest, len: limbs.len() };
+        d.len = (0..limbs.len()).rposition(|i| d.get(i) != L::ZERO).map_or(0, |i| i + 1);
+        d
+    }
+
+    /// Returns the limb `i` of the magnitude.
+    fn get(&self, i: usize) -> L {
+        if i >= self.len {
+            L::ZERO
+        } else if !self.negative || i < self.lowest {
+            self.limbs[i]
+        } else if i == self.lowest {
+            !self.limbs[i] + L::ONE
+        } else {
+            !self.limbs[i]
+        }
+    }
+
+    fn is_minus_one(&self) -> bool {
+        self.negative && self.len == 1 && self.get(0) == L::ONE
+    }
+
+    /// Returns the fractional part of a quotient from its remainder `r`.
+    fn fraction(&self, r: &[L]) -> Fraction {
+        if significant_len(r) == 0 {
+            return Fraction::Zero;
+        }
+        // Compare twice the remainder with the divisor.
+        for i in (0..=r.len().max(self.len)).rev() {
+            let twice = shifted(limb(r, i), limb_below(r, i), 1);
+            match twice.cmp(&self.get(i)) {
+                Ordering::Less => return Fraction::BelowHalf,
+                Ordering::Equal => {}
+                Ordering::Greater => return Fraction::AboveHalf,
+            }
+        }
+        Fraction::Half
+    }
+
+    /// Replaces `r` with the magnitude of the divisor minus `r`.
+    fn sub_from(&self, r: &mut [L]) {
+        let mut borrow = false;
+        for (i, limb) in r.iter_mut().enumerate() {
+            let (x, b1) = self.get(i).overflowing_sub(*limb);
+            let (x, b2) = x.overflowing_sub(from_bool(borrow));
+            *limb = x;
+            borrow = b1 || b2;
+        }
+    }
+}
+
+/// Divides the unsigned integer `n` by the magnitude of `d`.
+fn long_div<L: Limb>(n: &mut [L], d: &Divisor<'_, L>, q: &mut [L]) {
+    assert!(q.len() >= n.len(), "quotient buffer too short");
+    assert!(d.len != 0, "attempt to divide by zero");
+    for limb in q.iter_mut() {
+        *limb = L::ZERO;
+    }
+    let n_len = significant_len(n);
+    if n_len < d.len {
+        return;
+    }
+    if d.len == 1 {
+        q[..n_len].copy_from_slice(&n[..n_len]);
+        let r = div_rem(&mut q[..n_len], d.get(0));
+        for limb in n.iter_mut() {
+            *limb = L::ZERO;
+        }
+        n[0] = r;
+        return;
+    }
+
+    // The quotient digits are estimated from the leading limbs of the
+    // dividend and divisor, shifted so that the divisor's most significant
+    // bit is set. The shifted limbs are computed on the fly.
+    let shift = d.get(d.len - 1).leading_zeros();
+    let v1 = shifted(d.get(d.len - 1), d.get(d.len - 2), shift);
+    let v2 = shifted(d.get(d.len - 2), if d.len > 2 { d.get(d.len - 3) } else { L::ZERO }, shift);
+    for j in (0..=n_len - d.len).rev() {
+        let top = j + d.len;
+        let u0 = shifted(limb(n, top), limb(n, top - 1), shift);
+        let u1 = shifted(limb(n, top - 1), limb(n, top - 2), shift);
+        let u2 = shifted(limb(n, top - 2), limb_below(n, top - 2), shift);
+        // If `u0 == v1`, the estimate is capped to the largest digit.
+        let (mut qhat, mut rhat, mut rhat_overflow) = L::div_rem_wide(u0, u1, v1).map_or_else(
+            || {
+                let (rhat, overflow) = u1.overflowing_add(v1);
+                (L::MAX, rhat, overflow)
+            },
+            |(qhat, rhat)| (qhat, rhat, false),
+        );
+        while !rhat_overflow && qhat.mul_wide(v2) > (rhat, u2) {
+            qhat = qhat - L::ONE;
+            let (next, overflow) = rhat.overflowing_add(v1);
+            rhat = next;
+            rhat_overflow = overflow;
+        }
+
+        // Multiply and subtract.
+        let mut carry = L::ZERO;
+        let mut borrow = false;
+        for i in 0..d.len {
+            let (high, low) = qhat.mul_wide(d.get(i));
+            let (low, overflow) = low.overflowing_add(carry);
+            carry = high + from_bool(overflow);
+            let (limb_j, b1) = n[j + i].overflowing_sub(low);
+            let (limb_j, b2) = limb_j.overflowing_sub(from_bool(borrow));
+            n[j + i] = limb_j;
+            borrow = b1 || b2;
+        }
+        let (limb_j, b1) = limb(n, top).overflowing_sub(carry);
+        let (limb_j, b2) = limb_j.overflowing_sub(from_bool(borrow));
+        if top < n.len() {
+            n[top] = limb_j;
+        }
+
+        // The estimate was one too large, add back.
+        if b1 || b2 {
+            qhat = qhat - L::ONE;
+            let mut carry = false;
+            for i in 0..d.len {
+                let (limb_j, c1) = n[j + i].overflowing_add(d.get(i));
+                let (limb_j, c2) = limb_j.overflowing_add(from_bool(carry));
+                n[j + i] = limb_j;
+                carry = c1 || c2;
+            }
+            if top < n.len() {
+                n[top] = n[top].overflowing_add(from_bool(carry)).0;
+            }
+        }
+        q[j] = qhat;
+    }
+}
+
+/// Returns the limb `i` of `n`, or zero past the end.
+fn limb<L: Limb>(n: &[L], i: usize) -> L {
+    n.get(i).copied().unwrap_or(L::ZERO)
+}
+
+/// Returns the limb below `i` of `n`, or zero for the first limb.
+fn limb_below<L: Limb>(n: &[L], i: usize) -> L {
+    i.checked_sub(1).map_or(L::ZERO, |i| limb(n, i))
+}
+
+/// Returns the limb `high` shifted left by `s` bits, filled with the
+/// most significant bits of `low`.
+fn shifted<L: Limb>(high: L, low: L, s: u32) -> L {
+    if s == 0 {
+        high
+    } else {
+        (high << s) | (low >> (L::BITS - s))
+    }
+}
+
+const fn from_bool<L: Limb>(bit: bool) -> L {
+    if bit {
+        L::ONE
+    } else {
+        L::ZERO
+    }
+}
+
+/// Returns the number of limbs of `n` without the leading zero limbs.
+fn significant_len<L: Limb>(n: &[L]) -> usize {
+    n.iter().rposition(|&limb| limb != L::ZERO).map_or(0, |i| i + 1)
+}
+
+/// Returns whether the signed integer `n` is negative.
+fn is_negative<L: Limb>(n: &[L]) -> bool {
+    n.last().is_some_and(|&limb| limb > L::MAX / (L::ONE + L::ONE))
+}
+
+/// Returns the two's complement opposite of `x` if `negative`.
+fn magnitude<L: Limb>(x: L, negative: bool) -> L {
+    if negative && x != L::ZERO {
+        !x + L::ONE
+    } else {
+        x
+    }
+}
+
+/// Replaces `n` with its two's complement opposite.
+fn negate<L: Limb>(n: &mut [L]) {
+    let mut limbs = n.iter_mut();
+    // Low zero limbs are unchanged, the first non-zero one is negated,
+    // and the following ones are complemented.
+    for limb in &mut limbs {
+        if *limb != L::ZERO {
+            *limb = !*limb + L::ONE;
+            break;
+        }
+    }
+    for limb in limbs {
+        *limb = !*limb;
+    }
+}
+
+/// Adds one to the unsigned integer `n`, wrapping around.
+fn increment<L: Limb>(n: &mut [L]) {
+    for limb in n {
+        if *limb == L::MAX {
+            *limb = L::ZERO;
+        } else {
+            *limb = *limb + L::ONE;
+            return;
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{wide, DivInt, Gcd, RemEuclid};
+
+/// An integer modulo the compile-time modulus `M`.
+///
+/// The constructor normalises values into `[0, M)` with the euclidean
+/// remainder and the arithmetic operators stay in that range. The product
+/// is computed with 128 bits and never overflows.
+///
+/// Division multiplies by the modular inverse of the divisor and panics if
+/// it has none, that is if the divisor is not coprime with `M`. The
+/// [`checked_div`](Mod::checked_div) method returns `None` instead.
+///
+/// ```
+/// use divrem::Mod;
+///
+/// type M7 = Mod<7>;
+///
+/// let x = M7::new(-3);
+/// assert_eq!(x.value(), 4);
+/// assert_eq!(x * M7::new(5), M7::new(6));
+/// assert_eq!(M7::new(1) / x, M7::new(2));
+/// assert_eq!(x.pow(6), M7::new(1));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mod<const M: u64>(u64);
+
+impl<const M: u64> Mod<M> {
+    /// The modulus.
+    pub const MODULUS: u64 = M;
+
+    /// Returns `value` modulo `M`, normalised into `[0, M)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is zero.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // In [0, M).
+    pub fn new(value: i128) -> Self {
+        Mod(RemEuclid::rem_euclid(value, i128::from(M)) as u64)
+    }
+
+    /// Returns the value, in `[0, M)`.
+    #[inline]
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+}
+
+/// An integer modulo a modulus chosen at run time.
+///
+/// This is [`Mod`] with the modulus stored next to the value. It is
+/// implemented for the [`DivInt`] types that convert to and from `u128`,
+/// which includes the primitive integer types. Combining values with
+/// different moduli panics.
+///
+/// ```
+/// use divrem::DynMod;
+///
+/// let x = DynMod::new(-3_i64, 7);
+/// assert_eq!(x.value(), 4);
+/// assert_eq!(x * DynMod::new(5, 7), DynMod::new(6, 7));
+/// assert_eq!(x.inverse(), Some(DynMod::new(2, 7)));
+/// assert_eq!(DynMod::new(2_u8, 6).inverse(), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynMod<T> {
+    value: T,
+    modulus: T,
+}
+
+/// Access to the residue and the modulus as `u128`, shared by the
+/// operators of [`Mod`] and [`DynMod`].
+trait Residue: Copy {
+    /// Returns the residue and the modulus.
+    fn parts(self) -> (u128, u128);
+
+    /// Returns the residue `residue`, below the modulus of `self`.
+    #[must_use]
+    fn with(self, residue: u128) -> Self;
+}
+
+impl<const M: u64> Residue for Mod<M> {
+    #[inline]
+    fn parts(self) -> (u128, u128) {
+        (u128::from(self.0), u128::from(M))
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)] // Below `M`.
+    fn with(self, residue: u128) -> Self {
+        Mod(residue as u64)
+    }
+}
+
+/// Applies `f` to the residues of `x` and `y` and their common modulus.
+#[inline]
+fn combine<T: Residue>(x: T, y: T, op: fn(u128, u128, u128) -> u128) -> T {
+    let ((a, modulus), (b, other)) = (x.parts(), y.parts());
+    assert_eq!(modulus, other, "attempt to combine residues with different moduli");
+    x.with(op(a, b, modulus))
+}
+
+#[inline]
+const fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (sum, carry) = a.overflowing_add(b);
+    if carry || sum >= m {
+        sum.wrapping_sub(m)
+    } else {
+        sum
+    }
+}
+
+#[inline]
+const fn sub_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+#[inline]
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return RemEuclid::rem_euclid(product, m);
+    }
+    // `a, b < m` so the quotient fits in 128 bits.
+    let (high, low) = wide::mul_u128(a, b);
+    wide::div_rem_u256(high, low, m).1
+}
+
+fn pow_mod(mut base: u128, mut exp: u64, m: u128) -> u128 {
+    let mut result = RemEuclid::rem_euclid(1, m);
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp /= 2;
+    }
+    result
+}
+
+macro_rules! impl_ops {
+    ([$($g:tt)*] $t:ty, [$($bound:tt)*]) => {
+        impl<$($g)*> $t where $($bound)* {
+            /// Raises `self` to the power `exp`.
+            #[inline]
+            #[must_use]
+            pub fn pow(self, exp: u64) -> Self {
+                let (a, m) = self.parts();
+                self.with(pow_mod(a, exp, m))
+            }
+
+            /// Returns the modular inverse of `self`, or `None` if it has
+            /// none.
+            #[inline]
+            pub fn inverse(self) -> Option<Self> {
+                let (a, m) = self.parts();
+                Some(self.with(a.mod_inverse(m)?))
+            }
+
+            /// Divides `self` by `other`, or returns `None` if `other` has
+            /// no inverse.
+            #[inline]
+            pub fn checked_div(self, other: Self) -> Option<Self> {
+                Some(self * other.inverse()?)
+            }
+        }
+
+        impl<$($g)*> Add for $t where $($bound)* {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                combine(self, other, add_mod)
+            }
+        }
+
+        impl<$($g)*> Sub for $t where $($bound)* {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                combine(self, other, sub_mod)
+            }
+        }
+
+        impl<$($g)*> Mul for $t where $($bound)* {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                combine(self, other, mul_mod)
+            }
+        }
+
+        impl<$($g)*> Div for $t where $($bound)* {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, other: Self) -> Self {
+                let inverse = other.inverse();
+                let inverse =
+                    inverse.unwrap_or_else(|| panic!("attempt to divide by a non-invertible residue"));
+                combine(self, inverse, mul_mod)
+            }
+        }
+
+        impl<$($g)*> Neg for $t where $($bound)* {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                let (a, m) = self.parts();
+                self.with(sub_mod(0, a, m))
+            }
+        }
+
+        impl<$($g)*> fmt::Display for $t where $($bound)* {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.parts().0, f)
+            }
+        }
+
+        impl<$($g)*> fmt::Debug for $t where $($bound)* {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let (a, m) = self.parts();
+                write!(f, "{a} (mod {m})")
+            }
+        }
+    };
+}
+
+impl_ops!([const M: u64] Mod<M>, []);
+
+impl<T> DynMod<T>
+where T: DivInt + RemEuclid<Output = T>
+{
+    /// Returns `value` modulo `modulus`, normalised into `[0, modulus)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is not positive.
+    #[inline]
+    #[must_use]
+    pub fn new(value: T, modulus: T) -> Self {
+        assert!(modulus > T::ZERO, "attempt to use a non-positive modulus");
+        DynMod { value: RemEuclid::rem_euclid(value, modulus), modulus }
+    }
+
+    /// Returns the value, in `[0, modulus)`.
+    #[inline]
+    #[must_use]
+    pub const fn value(self) -> T {
+        self.value
+    }
+
+    /// Returns the modulus.
+    #[inline]
+    #[must_use]
+    pub const fn modulus(self) -> T {
+        self.modulus
+    }
+}
+
+impl<T> Residue for DynMod<T>
+where
+    T: DivInt + RemEuclid<Output = T> + TryFrom<u128>,
+    u128: TryFrom<T>,
+{
+    #[inline]
+    fn parts(self) -> (u128, u128) {
+        // Both are positive or zero.
+        let wide = |x: T| u128::try_from(x).unwrap_or_default();
+        (wide(self.value), wide(self.modulus))
+    }
+
+    #[inline]
+    fn with(self, residue: u128) -> Self {
+        let value = T::try_from(residue).unwrap_or(T::ZERO);
+        DynMod { value, modulus: self.modulus }
+    }
+}
+
+impl_ops!([T] DynMod<T>, [T: DivInt + RemEuclid<Output = T> + TryFrom<u128>, u128: TryFrom<T>]);
@@ -0,0 +1,144 @@
+use core::convert::{TryFrom, TryInto};
+
+use crate::rounding::{round_magnitude, Ceil, Euclid, Floor, Round, Trunc};
+use crate::{wide, Rounding};
+
+/// Multiplication followed by division without intermediate overflow.
+///
+/// `a.mul_div_by::<M>(b, c)` computes `a * b / c` rounded with mode `M`.
+/// The product is computed with twice the width of the type, so the result
+/// is exact as long as the final quotient fits in the type.
+///
+/// Every method returns `None` if `c == 0` or if the rounded quotient does
+/// not fit in the type.
+pub trait MulDiv: Sized {
+    /// Computes `self * mul / div` rounded with mode `M`.
+    fn mul_div_by<M: Rounding>(self, mul: Self, div: Self) -> Option<Self>;
+
+    /// Computes `self * mul / div` rounded towards zero.
+    #[inline]
+    fn mul_div_trunc(self, mul: Self, div: Self) -> Option<Self> {
+        self.mul_div_by::<Trunc>(mul, div)
+    }
+
+    /// Computes `self * mul / div` rounded towards negative infinity.
+    #[inline]
+    fn mul_div_floor(self, mul: Self, div: Self) -> Option<Self> {
+        self.mul_div_by::<Floor>(mul, div)
+    }
+
+    /// Computes `self * mul / div` rounded towards positive infinity.
+    #[inline]
+    fn mul_div_ceil(self, mul: Self, div: Self) -> Option<Self> {
+        self.mul_div_by::<Ceil>(mul, div)
+    }
+
+    /// Computes the euclidean quotient of `self * mul` by `div`.
+    #[inline]
+    fn mul_div_euclid(self, mul: Self, div: Self) -> Option<Self> {
+        self.mul_div_by::<Euclid>(mul, div)
+    }
+
+    /// Computes `self * mul / div` rounded to the nearest integer,
+    /// with ties rounded away from zero.
+    #[inline]
+    fn mul_div_round(self, mul: Self, div: Self) -> Option<Self> {
+        self.mul_div_by::<Round>(mul, div)
+    }
+}
+
+/// Converts the magnitude `$q` to `$t` with the sign given by `$negative`.
+macro_rules! from_magnitude {
+    ($t:ty, $q:expr, $negative:expr) => {
+        if $negative {
+            (0 as $t).checked_sub_unsigned($q.try_into().ok()?)
+        } else {
+            <$t>::try_from($q).ok()
+        }
+    };
+}
+
+macro_rules! impl_mul_div {
+    (unsigned: $($t:ty => $w:ty),*) => {$(
+        impl MulDiv for $t {
+            #[inline]
+            fn mul_div_by<M: Rounding>(self, mul: Self, div: Self) -> Option<Self> {
+                if div == 0 {
+                    return None;
+                }
+                let n = <$w>::from(self) * <$w>::from(mul);
+                let d = <$w>::from(div);
+                let q = round_magnitude::<M, $w>(n / d, n % d, d, false, false)?;
+                <$t>::try_from(q).ok()
+            }
+        }
+    )*};
+    (signed: $($t:ty => $w:ty),*) => {$(
+        impl MulDiv for $t {
+            #[inline]
+            fn mul_div_by<M: Rounding>(self, mul: Self, div: Self) -> Option<Self> {
+                if div == 0 {
+                    return None;
+                }
+                let dividend_negative = self != 0 && mul != 0 && (self < 0) != (mul < 0);
+                let negative = dividend_negative != (div < 0);
+                let n = <$w>::from(self.unsigned_abs()) * <$w>::from(mul.unsigned_abs());
+                let d = <$w>::from(div.unsigned_abs());
+                let q = round_magnitude::<M, $w>(n / d, n % d, d, negative, dividend_negative)?;
+                from_magnitude!($t, q, negative)
+            }
+        }
+    )*};
+}
+
+impl_mul_div!(unsigned: u8 => u16, u16 => u32, u32 => u64, u64 => u128);
+impl_mul_div!(signed: i8 => u16, i16 => u32, i32 => u64, i64 => u128);
+
+impl MulDiv for u128 {
+    #[inline]
+    fn mul_div_by<M: Rounding>(self, mul: Self, div: Self) -> Option<Self> {
+        if div == 0 {
+            return None;
+        }
+        let (high, low) = wide::mul_u128(self, mul);
+        if high >= div {
+            return None;
+        }
+        let (q, r) = wide::div_rem_u256(high, low, div);
+        round_magnitude::<M, u128>(q, r, div, false, false)
+    }
+}
+
+impl MulDiv for i128 {
+    #[inline]
+    fn mul_div_by<M: Rounding>(self, mul: Self, div: Self) -> Option<Self> {
+        if div == 0 {
+            return None;
+        }
+        let dividend_negative = self != 0 && mul != 0 && (self < 0) != (mul < 0);
+        let negative = dividend_negative != (div < 0);
+        let (high, low) = wide::mul_u128(self.unsigned_abs(), mul.unsigned_abs());
+        let d = div.unsigned_abs();
+        if high >= d {
+            return None;
+        }
+        let (q, r) = wide::div_rem_u256(high, low, d);
+        let q = round_magnitude::<M, u128>(q, r, d, negative, dividend_negative)?;
+        from_magnitude!(i128, q, negative)
+    }
+}
+
+macro_rules! impl_mul_div_size {
+    ($($t:ty => $f:ty),*) => {$(
+        impl MulDiv for $t {
+            #[inline]
+            fn mul_div_by<M: Rounding>(self, mul: Self, div: Self) -> Option<Self> {
+                let (a, b, c) = (<$f>::try_from(self), <$f>::try_from(mul), <$f>::try_from(div));
+                let q = a.ok()?.mul_div_by::<M>(b.ok()?, c.ok()?)?;
+                <$t>::try_from(q).ok()
+            }
+        }
+    )*};
+}
+
+impl_mul_div_size!(usize => u64, isize => i64);
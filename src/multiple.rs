@@ -0,0 +1,188 @@
+use core::cmp::Ordering;
+
+use crate::rounding::sealed::{Fraction, Mode};
+use crate::rounding::{Ceil, Floor, Round};
+use crate::{DivError, DivInt, Rounding, TryDivRem};
+
+/// Rounding to a multiple of an integer.
+///
+/// `x.round_to_multiple_by::<M>(m)` is `m` times the quotient of `x` by `m`
+/// rounded with mode `M`, that is `x` minus the matching remainder. The
+/// direction therefore depends on the sign of `m` for [`Floor`] and
+/// [`Ceil`]: `(-7).round_to_multiple_by::<Floor>(-4)` is `-4`. The
+/// [`round_down_to_multiple`](RoundToMultiple::round_down_to_multiple) and
+/// [`round_up_to_multiple`](RoundToMultiple::round_up_to_multiple) methods
+/// pick the mode from the sign of `m` so that they always go down and up.
+///
+/// The checked methods return `None` if `m` is zero or if the result does
+/// not fit in the type.
+///
+/// This trait is implemented for all types implementing [`DivInt`].
+pub trait RoundToMultiple: DivInt {
+    /// Rounds `self` to a multiple of `m` with rounding mode `M`.
+    #[inline]
+    fn checked_round_to_multiple_by<M: Rounding>(self, m: Self) -> Option<Self> {
+        let multiple = match M::MODE {
+            Mode::Trunc => self.try_rem(m).map(|r| self.checked_sub(r)),
+            Mode::Floor => self.try_rem_floor(m).map(|r| self.checked_sub(r)),
+            Mode::Euclid => self.try_rem_euclid(m).map(|r| self.checked_sub(r)),
+            // The ceiled remainders of unsigned types are negative.
+            Mode::Ceil => self.try_div_ceil(m).map(|q| q.checked_mul(m)),
+            Mode::Nearest => return round_to_nearest::<M, Self>(self, m),
+        };
+        match multiple {
+            Ok(multiple) => multiple,
+            Err(DivError::DivisionByZero) => None,
+            // Every integer is a multiple of -1.
+            Err(DivError::Overflow) => Some(self),
+        }
+    }
+
+    /// Rounds `self` down to the largest multiple of `m` less than or equal
+    /// to `self`.
+    #[inline]
+    fn checked_round_down_to_multiple(self, m: Self) -> Option<Self> {
+        if m < Self::ZERO {
+            self.checked_round_to_multiple_by::<Ceil>(m)
+        } else {
+            self.checked_round_to_multiple_by::<Floor>(m)
+        }
+    }
+
+    /// Rounds `self` up to the smallest multiple of `m` greater than or
+    /// equal to `self`.
+    #[inline]
+    fn checked_round_up_to_multiple(self, m: Self) -> Option<Self> {
+        if m < Self::ZERO {
+            self.checked_round_to_multiple_by::<Floor>(m)
+        } else {
+            self.checked_round_to_multiple_by::<Ceil>(m)
+        }
+    }
+
+    /// Rounds `self` to the nearest multiple of `m`, with ties rounded away
+    /// from zero.
+    #[inline]
+    fn checked_round_to_nearest_multiple(self, m: Self) -> Option<Self> {
+        self.checked_round_to_multiple_by::<Round>(m)
+    }
+
+    /// Rounds `self` to a multiple of `m` with rounding mode `M`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero or if the result overflows.
+    #[inline]
+    #[must_use]
+    fn round_to_multiple_by<M: Rounding>(self, m: Self) -> Self {
+        unwrap(self.checked_round_to_multiple_by::<M>(m), m)
+    }
+
+    /// Rounds `self` down to the largest multiple of `m` less than or equal
+    /// to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero or if the result overflows.
+    #[inline]
+    #[must_use]
+    fn round_down_to_multiple(self, m: Self) -> Self {
+        unwrap(self.checked_round_down_to_multiple(m), m)
+    }
+
+    /// Rounds `self` up to the smallest multiple of `m` greater than or
+    /// equal to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero or if the result overflows.
+    #[inline]
+    #[must_use]
+    fn round_up_to_multiple(self, m: Self) -> Self {
+        unwrap(self.checked_round_up_to_multiple(m), m)
+    }
+
+    /// Rounds `self` to the nearest multiple of `m`, with ties rounded away
+    /// from zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero or if the result overflows.
+    #[inline]
+    #[must_use]
+    fn round_to_nearest_multiple(self, m: Self) -> Self {
+        unwrap(self.checked_round_to_nearest_multiple(m), m)
+    }
+
+    /// Returns whether `self` is a multiple of `m`.
+    ///
+    /// Zero is the only multiple of zero. The unsigned primitive types have
+    /// an inherent method with the same name and definition.
+    #[inline]
+    fn is_multiple_of(self, m: Self) -> bool {
+        if m == Self::ZERO {
+            self == Self::ZERO
+        } else {
+            self.checked_rem(m).unwrap_or(Self::ZERO) == Self::ZERO
+        }
+    }
+}
+
+impl<T: DivInt> RoundToMultiple for T {}
+
+/// Rounds `x` to the nearest multiple of `m` with ties broken by mode `M`.
+///
+/// There are no division traits for the nearest modes, so the truncated
+/// multiple is moved away from zero according to the distance to the next.
+#[inline]
+fn round_to_nearest<M: Rounding, T: DivInt>(x: T, m: T) -> Option<T> {
+    if m == T::ZERO {
+        return None;
+    }
+    // Every integer is a multiple of -1.
+    let Some(q) = x.checked_div(m) else {
+        return Some(x);
+    };
+    let r = x - q * m;
+    let multiple = x - r;
+    if r == T::ZERO {
+        return Some(multiple);
+    }
+    // The distance from `x` to the next multiple away from zero, with the
+    // sign of `m`.
+    let negative = (x < T::ZERO) != (m < T::ZERO);
+    let rest = if negative { m + r } else { m - r };
+    let fraction = Fraction::from_cmp(cmp_magnitude(r, rest));
+    let odd = q % (T::ONE + T::ONE) != T::ZERO;
+    if !M::round_up(negative, x < T::ZERO, odd, fraction) {
+        Some(multiple)
+    } else if negative {
+        multiple.checked_sub(m)
+    } else {
+        multiple.checked_add(m)
+    }
+}
+
+/// Compares the magnitudes of `a` and `b` without overflowing on `MIN`.
+#[inline]
+pub fn cmp_magnitude<T: DivInt>(a: T, b: T) -> Ordering {
+    if T::IS_SIGNED {
+        // Negating a positive value never overflows.
+        let nonpositive = |x: T| if x > T::ZERO { T::ZERO - x } else { x };
+        nonpositive(b).cmp(&nonpositive(a))
+    } else {
+        a.cmp(&b)
+    }
+}
+
+/// Unwraps the result of a checked method with divisor `m`.
+#[inline]
+fn unwrap<T: DivInt>(result: Option<T>, m: T) -> T {
+    result.unwrap_or_else(|| {
+        if m == T::ZERO {
+            panic!("attempt to divide by zero")
+        } else {
+            panic!("attempt to round to a multiple with overflow")
+        }
+    })
+}
@@ -0,0 +1,290 @@
+use core::convert::TryFrom;
+use core::ops::Neg;
+
+use crate::rounding::sealed::Mode;
+use crate::rounding::{round_magnitude, Ceil, Euclid, Floor, Round, Trunc};
+use crate::Rounding;
+
+/// Division by a power of two using shifts and masks.
+///
+/// `x.div_floor_pow2(k)` computes `x` divided by `2^k`, like
+/// `x.floor_div(1 << k)` but without a division instruction. Every mode of
+/// the crate is available, with the same definitions as for the division
+/// traits, plus rounding to the nearest integer with ties away from zero.
+///
+/// Remainders rounded up with [`Ceil`], [`Round`] or
+/// [`HalfEven`](crate::rounding::HalfEven) are negative, so their forms are
+/// only available for signed types.
+///
+/// The checked forms return `None` if `k >= BITS`, or if the remainder of an
+/// unsigned type would be negative.
+pub trait DivPow2: Sized {
+    /// Divides `self` by `2^k` with rounding mode `M`.
+    fn checked_div_pow2_by<M: Rounding>(self, k: u32) -> Option<Self>;
+
+    /// Divides `self` by `2^k` with rounding mode `M`, returning the
+    /// quotient and the remainder.
+    fn checked_div_rem_pow2_by<M: Rounding>(self, k: u32) -> Option<(Self, Self)>;
+
+    /// Returns the remainder of `self` divided by `2^k` with rounding mode `M`.
+    #[inline]
+    fn checked_rem_pow2_by<M: Rounding>(self, k: u32) -> Option<Self> {
+        self.checked_div_rem_pow2_by::<M>(k).map(|(_, r)| r)
+    }
+
+    /// Divides `self` by `2^k`, rounding towards zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_trunc_pow2(self, k: u32) -> Self {
+        in_range(self.checked_div_pow2_by::<Trunc>(k))
+    }
+
+    /// Divides `self` by `2^k`, rounding towards negative infinity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_floor_pow2(self, k: u32) -> Self {
+        in_range(self.checked_div_pow2_by::<Floor>(k))
+    }
+
+    /// Divides `self` by `2^k`, rounding towards positive infinity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_ceil_pow2(self, k: u32) -> Self {
+        in_range(self.checked_div_pow2_by::<Ceil>(k))
+    }
+
+    /// Computes the euclidean quotient of `self` by `2^k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_euclid_pow2(self, k: u32) -> Self {
+        in_range(self.checked_div_pow2_by::<Euclid>(k))
+    }
+
+    /// Divides `self` by `2^k`, rounding to the nearest integer with ties
+    /// rounded away from zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_round_pow2(self, k: u32) -> Self {
+        in_range(self.checked_div_pow2_by::<Round>(k))
+    }
+
+    /// Returns the remainder of [`div_trunc_pow2`](DivPow2::div_trunc_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn rem_trunc_pow2(self, k: u32) -> Self {
+        in_range(self.checked_rem_pow2_by::<Trunc>(k))
+    }
+
+    /// Returns the remainder of [`div_floor_pow2`](DivPow2::div_floor_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn rem_floor_pow2(self, k: u32) -> Self {
+        in_range(self.checked_rem_pow2_by::<Floor>(k))
+    }
+
+    /// Returns the remainder of [`div_ceil_pow2`](DivPow2::div_ceil_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn rem_ceil_pow2(self, k: u32) -> Self
+    where Self: Neg<Output = Self> {
+        in_range(self.checked_rem_pow2_by::<Ceil>(k))
+    }
+
+    /// Returns the remainder of [`div_euclid_pow2`](DivPow2::div_euclid_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn rem_euclid_pow2(self, k: u32) -> Self {
+        in_range(self.checked_rem_pow2_by::<Euclid>(k))
+    }
+
+    /// Returns the remainder of [`div_round_pow2`](DivPow2::div_round_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn rem_round_pow2(self, k: u32) -> Self
+    where Self: Neg<Output = Self> {
+        in_range(self.checked_rem_pow2_by::<Round>(k))
+    }
+
+    /// Returns both [`div_trunc_pow2`](DivPow2::div_trunc_pow2) and
+    /// [`rem_trunc_pow2`](DivPow2::rem_trunc_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_rem_trunc_pow2(self, k: u32) -> (Self, Self) {
+        in_range(self.checked_div_rem_pow2_by::<Trunc>(k))
+    }
+
+    /// Returns both [`div_floor_pow2`](DivPow2::div_floor_pow2) and
+    /// [`rem_floor_pow2`](DivPow2::rem_floor_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_rem_floor_pow2(self, k: u32) -> (Self, Self) {
+        in_range(self.checked_div_rem_pow2_by::<Floor>(k))
+    }
+
+    /// Returns both [`div_ceil_pow2`](DivPow2::div_ceil_pow2) and
+    /// [`rem_ceil_pow2`](DivPow2::rem_ceil_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_rem_ceil_pow2(self, k: u32) -> (Self, Self)
+    where Self: Neg<Output = Self> {
+        in_range(self.checked_div_rem_pow2_by::<Ceil>(k))
+    }
+
+    /// Returns both [`div_euclid_pow2`](DivPow2::div_euclid_pow2) and
+    /// [`rem_euclid_pow2`](DivPow2::rem_euclid_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_rem_euclid_pow2(self, k: u32) -> (Self, Self) {
+        in_range(self.checked_div_rem_pow2_by::<Euclid>(k))
+    }
+
+    /// Returns both [`div_round_pow2`](DivPow2::div_round_pow2) and
+    /// [`rem_round_pow2`](DivPow2::rem_round_pow2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= BITS`.
+    #[inline]
+    #[must_use]
+    fn div_rem_round_pow2(self, k: u32) -> (Self, Self)
+    where Self: Neg<Output = Self> {
+        in_range(self.checked_div_rem_pow2_by::<Round>(k))
+    }
+}
+
+/// Unwraps the result of a checked form whose only failure is `k >= BITS`.
+#[inline]
+fn in_range<T>(result: Option<T>) -> T {
+    result.unwrap_or_else(|| panic!("attempt to shift right with overflow"))
+}
+
+macro_rules! impl_div_pow2 {
+    (unsigned: $($t:ty),*) => {$(
+        impl DivPow2 for $t {
+            #[inline]
+            fn checked_div_pow2_by<M: Rounding>(self, k: u32) -> Option<Self> {
+                if k >= <$t>::BITS {
+                    return None;
+                }
+                let mask = !(<$t>::MAX << k);
+                match M::MODE {
+                    Mode::Trunc | Mode::Floor | Mode::Euclid => Some(self >> k),
+                    Mode::Ceil => Some((self >> k) + <$t>::from(self & mask != 0)),
+                    Mode::Nearest => {
+                        round_magnitude::<M, $t>(self >> k, self & mask, mask + 1, false, false)
+                    }
+                }
+            }
+
+            #[inline]
+            fn checked_div_rem_pow2_by<M: Rounding>(self, k: u32) -> Option<(Self, Self)> {
+                let q = self.checked_div_pow2_by::<M>(k)?;
+                // A quotient rounded up leaves a negative remainder.
+                if q > self >> k {
+                    return None;
+                }
+                Some((q, self - (q << k)))
+            }
+        }
+    )*};
+    (signed: $($t:ty => $u:ty),*) => {$(
+        impl DivPow2 for $t {
+            #[inline]
+            fn checked_div_pow2_by<M: Rounding>(self, k: u32) -> Option<Self> {
+                self.checked_div_rem_pow2_by::<M>(k).map(|(q, _)| q)
+            }
+
+            #[inline]
+            fn checked_div_rem_pow2_by<M: Rounding>(self, k: u32) -> Option<(Self, Self)> {
+                if k >= <$t>::BITS {
+                    return None;
+                }
+                let mask: $t = !(!0 << k);
+                // The arithmetic shift is the floored quotient.
+                let (q, r) = (self >> k, self & mask);
+                let q = match M::MODE {
+                    // The divisor is positive.
+                    Mode::Floor | Mode::Euclid => return Some((q, r)),
+                    // Negative dividends are biased by `2^k - 1`.
+                    Mode::Trunc => (self + ((self >> (<$t>::BITS - 1)) & mask)) >> k,
+                    // `q` is at most `MAX / 2` if `r` is not zero.
+                    Mode::Ceil => q + <$t>::from(r != 0),
+                    Mode::Nearest => {
+                        let negative = self < 0;
+                        let n = self.unsigned_abs();
+                        let mask = !(<$u>::MAX << k);
+                        let q = round_magnitude::<M, $u>(
+                            n >> k, n & mask, mask + 1, negative, negative,
+                        )?;
+                        if negative {
+                            (0 as $t).checked_sub_unsigned(q)?
+                        } else {
+                            <$t>::try_from(q).ok()?
+                        }
+                    }
+                };
+                // The remainder fits, even if `q << k` wraps around.
+                Some((q, self.wrapping_sub(q << k)))
+            }
+        }
+    )*};
+}
+
+impl_div_pow2!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_div_pow2!(signed: i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
@@ -0,0 +1,18 @@
+//! Re-exports of all the traits of this crate.
+//!
+//! ```
+//! use divrem::prelude::*;
+//!
+//! fn floor_ceil<T: DivisionVariants + Copy>(x: T, y: T) -> (T, T) {
+//!     (x.div_floor(y), x.div_ceil(y))
+//! }
+//!
+//! assert_eq!(floor_ceil(-7, 2), (-4, -3));
+//! ```
+
+pub use crate::rounding::Rounding;
+pub use crate::{
+    DivBy, DivCeil, DivEuclid, DivFloor, DivInt, DivPow2, DivRem, DivRemBy, DivRemCeil,
+    DivRemEuclid, DivRemExt, DivRemFloor, DivRemWide, DivRemWideCeil, DivSum, DivisionVariants,
+    Gcd, ILog, MulDiv, RemBy, RemCeil, RemEuclid, RemFloor, Roots, RoundToMultiple, TryDivRem,
+};
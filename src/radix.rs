@@ -0,0 +1,167 @@
+//! Mixed-radix decomposition and composition.
+//!
+//! A mixed-radix number has a radix per digit, as `(days, hours, minutes,
+//! seconds)` with the radices `[24, 60, 60]`. [`decompose_floor`] and the
+//! other decompositions split a value into its most significant part, left
+//! unbounded, and one digit per radix, the radices and the digits being
+//! ordered from the most significant. Each digit is the remainder of a
+//! division by its radix in the chosen rounding mode, so that a negative
+//! duration decomposes into a negative number of days and non-negative
+//! hours, minutes and seconds with floored or euclidean division. [`compose`]
+//! is the inverse.
+//!
+//! ```
+//! use divrem::radix::{compose, decompose, decompose_floor};
+//!
+//! let radices = [24, 60, 60];
+//! assert_eq!(decompose_floor(90_061, &radices), Some((1, [1, 1, 1])));
+//! assert_eq!(decompose_floor(-1, &radices), Some((-1, [23, 59, 59])));
+//! assert_eq!(decompose(-1, &radices), Some((0, [0, 0, -1])));
+//! assert_eq!(compose(-1, &[23, 59, 59], &radices), Some(-1));
+//!
+//! // A linear index into `(z, y, x)` for a `4 × 3 × 5` array.
+//! assert_eq!(decompose_floor(37_usize, &[3, 5]), Some((2, [1, 2])));
+//! assert_eq!(compose(2_usize, &[1, 2], &[3, 5]), Some(37));
+//! ```
+
+use crate::rounding::{Ceil, Euclid, Floor, Trunc};
+use crate::{DivInt, DivRem, DivRemBy, Rounding};
+
+/// Decomposes `value` in the mixed radix `radices` with truncated division.
+///
+/// Returns the most significant part and the digits, or `None` if a radix
+/// is zero or if a quotient does not fit in the type. The digits have the
+/// sign of `value`.
+#[inline]
+pub fn decompose<T, const N: usize>(value: T, radices: &[T; N]) -> Option<(T, [T; N])>
+where T: DivInt + DivRem<Output = (T, T)> + DivRemBy<Trunc> {
+    decompose_by::<Trunc, T, N>(value, radices)
+}
+
+/// Decomposes `value` in the mixed radix `radices` with floored division.
+///
+/// Returns the most significant part and the digits, or `None` if a radix
+/// is zero or if a quotient does not fit in the type. The digits have the
+/// sign of their radix.
+#[inline]
+pub fn decompose_floor<T, const N: usize>(value: T, radices: &[T; N]) -> Option<(T, [T; N])>
+where T: DivInt + DivRem<Output = (T, T)> + DivRemBy<Floor> {
+    decompose_by::<Floor, T, N>(value, radices)
+}
+
+/// Decomposes `value` in the mixed radix `radices` with ceiled division.
+///
+/// Returns the most significant part and the digits, or `None` if a radix
+/// is zero or if a quotient does not fit in the type. The digits have the
+/// opposite sign of their radix.
+#[inline]
+pub fn decompose_ceil<T, const N: usize>(value: T, radices: &[T; N]) -> Option<(T, [T; N])>
+where T: DivInt + DivRem<Output = (T, T)> + DivRemBy<Ceil> {
+    decompose_by::<Ceil, T, N>(value, radices)
+}
+
+/// Decomposes `value` in the mixed radix `radices` with euclidean division.
+///
+/// Returns the most significant part and the digits, or `None` if a radix
+/// is zero or if a quotient does not fit in the type. The digits are never
+/// negative, even for negative radices: `[-2; N]` is the negabinary base.
+#[inline]
+pub fn decompose_euclid<T, const N: usize>(value: T, radices: &[T; N]) -> Option<(T, [T; N])>
+where T: DivInt + DivRem<Output = (T, T)> + DivRemBy<Euclid> {
+    decompose_by::<Euclid, T, N>(value, radices)
+}
+
+/// Decomposes `value` in the mixed radix `radices` with rounding mode `M`.
+///
+/// Returns the most significant part and the digits, or `None` if a radix
+/// is zero or if a quotient does not fit in the type.
+#[inline]
+pub fn decompose_by<M, T, const N: usize>(value: T, radices: &[T; N]) -> Option<(T, [T; N])>
+where
+    M: Rounding,
+    T: DivInt + DivRem<Output = (T, T)> + DivRemBy<M>,
+{
+    let mut digits = [T::ZERO; N];
+    let high = decompose_into_by::<M, T>(value, radices, &mut digits)?;
+    Some((high, digits))
+}
+
+/// Decomposes `value` in the mixed radix `radices` with rounding mode `M`,
+/// writing the digits into `digits`.
+///
+/// Returns the most significant part, or `None` if a radix is zero or if a
+/// quotient does not fit in the type, in which case `digits` holds the
+/// digits computed so far.
+///
+/// # Panics
+///
+/// Panics if `digits` and `radices` have different lengths.
+#[inline]
+pub fn decompose_into_by<M, T>(mut value: T, radices: &[T], digits: &mut [T]) -> Option<T>
+where
+    M: Rounding,
+    T: DivInt + DivRem<Output = (T, T)> + DivRemBy<M>,
+{
+    assert_eq!(digits.len(), radices.len(), "digits and radices have different lengths");
+    for (digit, &radix) in digits.iter_mut().zip(radices).rev() {
+        if radix == T::ZERO {
+            return None;
+        }
+        // Every mode divides exactly by -1, `MIN / -1` overflows.
+        (value, *digit) = if T::IS_SIGNED && radix == T::ZERO - T::ONE {
+            (T::ZERO.checked_sub(value)?, T::ZERO)
+        } else {
+            value.div_rem_by(radix)
+        };
+    }
+    Some(value)
+}
+
+/// Composes the value with the most significant part `high` and the digits
+/// `digits` in the mixed radix `radices`.
+///
+/// This is the inverse of the decompositions, in any rounding mode: the
+/// value is `high` times the product of the radices plus each digit times
+/// the product of the radices after it. The digits are not required to be
+/// smaller than their radix. Returns `None` if the value does not fit in the
+/// type, even if some products do not fit on their own.
+///
+/// # Panics
+///
+/// Panics if `digits` and `radices` have different lengths.
+#[inline]
+pub fn compose<T: DivInt>(high: T, digits: &[T], radices: &[T]) -> Option<T> {
+    assert_eq!(digits.len(), radices.len(), "digits and radices have different lengths");
+    digits
+        .iter()
+        .zip(radices)
+        .try_fold(high, |value, (&digit, &radix)| mul_add(value, radix, digit))
+}
+
+/// Returns `a * b + c`, or `None` if it does not fit in the type.
+///
+/// `a * b` may overflow while the sum fits if `c` has the opposite sign, as
+/// with the digits of a ceiled decomposition or digits larger than their
+/// radix. The truncated quotient of `c` by `b` is then moved to `a`, and one
+/// more `b` from the product to the remainder if the product still
+/// overflows.
+#[inline]
+fn mul_add<T: DivInt>(a: T, b: T, c: T) -> Option<T> {
+    if let Some(product) = a.checked_mul(b) {
+        return product.checked_add(c);
+    }
+    // `b` is not zero since the product overflows, and `c / -1` may overflow.
+    if T::IS_SIGNED && b == T::ZERO - T::ONE {
+        return c.checked_sub(a);
+    }
+    // If the sum fits, `c / b` has the opposite sign of `a`.
+    let (a, c) = (a.checked_add(c / b)?, c % b);
+    if let Some(product) = a.checked_mul(b) {
+        return product.checked_add(c);
+    }
+    if a > T::ZERO {
+        (a - T::ONE).checked_mul(b)?.checked_add(c.checked_add(b)?)
+    } else {
+        (a + T::ONE).checked_mul(b)?.checked_add(c.checked_sub(b)?)
+    }
+}
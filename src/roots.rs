@@ -0,0 +1,236 @@
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+
+use crate::rounding::sealed::Fraction;
+use crate::rounding::{Ceil, Floor, Round};
+use crate::wide::U512;
+use crate::Rounding;
+
+/// Integer roots with a rounding mode.
+///
+/// `x.nth_root_by::<M>(n)` is the real `n`th root of `x` rounded with mode
+/// `M`. The roots are exact, they are computed without floating point
+/// intermediates. Rounding to nearest never ties for `n >= 2`.
+///
+/// Every method returns `None` if `n` is zero, or if `n` is even and `self`
+/// is negative.
+pub trait Roots: Sized {
+    /// Returns the `n`th root of `self` rounded with mode `M`.
+    fn nth_root_by<M: Rounding>(self, n: u32) -> Option<Self>;
+
+    /// Returns the square root of `self` rounded towards negative infinity.
+    #[inline]
+    fn sqrt_floor(self) -> Option<Self> {
+        self.nth_root_by::<Floor>(2)
+    }
+
+    /// Returns the square root of `self` rounded towards positive infinity.
+    #[inline]
+    fn sqrt_ceil(self) -> Option<Self> {
+        self.nth_root_by::<Ceil>(2)
+    }
+
+    /// Returns the square root of `self` rounded to the nearest integer.
+    #[inline]
+    fn sqrt_round(self) -> Option<Self> {
+        self.nth_root_by::<Round>(2)
+    }
+
+    /// Returns the cube root of `self` rounded towards negative infinity.
+    #[inline]
+    fn cbrt_floor(self) -> Option<Self> {
+        self.nth_root_by::<Floor>(3)
+    }
+
+    /// Returns the cube root of `self` rounded towards positive infinity.
+    #[inline]
+    fn cbrt_ceil(self) -> Option<Self> {
+        self.nth_root_by::<Ceil>(3)
+    }
+
+    /// Returns the cube root of `self` rounded to the nearest integer.
+    #[inline]
+    fn cbrt_round(self) -> Option<Self> {
+        self.nth_root_by::<Round>(3)
+    }
+
+    /// Returns the `n`th root of `self` rounded towards negative infinity.
+    #[inline]
+    fn nth_root_floor(self, n: u32) -> Option<Self> {
+        self.nth_root_by::<Floor>(n)
+    }
+
+    /// Returns the `n`th root of `self` rounded towards positive infinity.
+    #[inline]
+    fn nth_root_ceil(self, n: u32) -> Option<Self> {
+        self.nth_root_by::<Ceil>(n)
+    }
+
+    /// Returns the `n`th root of `self` rounded to the nearest integer.
+    #[inline]
+    fn nth_root_round(self, n: u32) -> Option<Self> {
+        self.nth_root_by::<Round>(n)
+    }
+}
+
+/// Integer logarithms with a rounding mode.
+///
+/// `x.ilog_by::<M>(base)` is the logarithm of `x` in base `base` rounded
+/// with mode `M`. Rounding to nearest happens on the logarithmic scale:
+/// `x` rounds up from `k` when `x >= base^(k + 1/2)`, with ties rounded up.
+///
+/// Every method returns `None` if `self` is not positive or if `base` is
+/// less than 2.
+pub trait ILog: Sized {
+    /// Returns the logarithm of `self` in base `base` rounded with mode `M`.
+    fn ilog_by<M: Rounding>(self, base: Self) -> Option<u32>;
+
+    /// Returns the logarithm of `self` in base `base` rounded towards
+    /// negative infinity.
+    #[inline]
+    fn ilog_floor(self, base: Self) -> Option<u32> {
+        self.ilog_by::<Floor>(base)
+    }
+
+    /// Returns the logarithm of `self` in base `base` rounded towards
+    /// positive infinity.
+    #[inline]
+    fn ilog_ceil(self, base: Self) -> Option<u32> {
+        self.ilog_by::<Ceil>(base)
+    }
+
+    /// Returns the logarithm of `self` in base `base` rounded to the nearest
+    /// integer.
+    #[inline]
+    fn ilog_round(self, base: Self) -> Option<u32> {
+        self.ilog_by::<Round>(base)
+    }
+}
+
+/// Returns the largest `r` such that `rⁿ <= x`, for `n >= 1`.
+fn floor_root(x: u128, n: u32) -> u128 {
+    if n == 1 || x < 2 {
+        return x;
+    }
+    // The root has at most ⌈bits / n⌉ bits: lo^n <= x < hi^n.
+    let bits = 128 - x.leading_zeros();
+    let (mut lo, mut hi) = (1_u128, 1 << ((bits - 1) / n + 1));
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if mid.checked_pow(n).is_some_and(|power| power <= x) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Compares `x` with `(r + 1/2)ⁿ`, for `r >= 1`.
+fn cmp_root_midpoint(x: u128, r: u128, n: u32) -> Ordering {
+    // Compares 2ⁿx with (2r + 1)ⁿ, stopping as soon as the power is larger.
+    let cmp = || {
+        let scaled = U512::new(x).checked_shl(n)?;
+        let mut power = U512::new(1);
+        for _ in 0..n {
+            power = power.checked_mul(2 * r + 1)?;
+            if power > scaled {
+                return Some(Ordering::Less);
+            }
+        }
+        Some(scaled.cmp(&power))
+    };
+    // Otherwise `n` is large and (r + 1/2)ⁿ >= 1.5ⁿ is larger than `x`.
+    cmp().unwrap_or(Ordering::Less)
+}
+
+/// Returns the magnitude of the `n`th root of `x`, negated if `negative`,
+/// rounded with mode `M`.
+fn root_magnitude<M: Rounding>(x: u128, n: u32, negative: bool) -> Option<u128> {
+    if n == 0 {
+        return None;
+    }
+    let root = floor_root(x, n);
+    let fraction = if root.pow(n) == x {
+        Fraction::Zero
+    } else {
+        Fraction::from_cmp(cmp_root_midpoint(x, root, n))
+    };
+    if M::round_up(negative, negative, root % 2 == 1, fraction) {
+        Some(root + 1)
+    } else {
+        Some(root)
+    }
+}
+
+/// Returns the logarithm of `x` in base `base` rounded with mode `M`.
+fn log<M: Rounding>(x: u128, base: u128) -> Option<u32> {
+    if x == 0 || base < 2 {
+        return None;
+    }
+    let (mut k, mut power) = (0, 1);
+    while power <= x / base {
+        power *= base;
+        k += 1;
+    }
+    let fraction = if power == x {
+        Fraction::Zero
+    } else {
+        // Compares x² with base^(2k + 1), neither product overflows.
+        let square = U512::new(x).checked_mul(x);
+        let midpoint = U512::new(power).checked_mul(power).and_then(|p| p.checked_mul(base));
+        Fraction::from_cmp(square.cmp(&midpoint))
+    };
+    if M::round_up(false, false, k % 2 == 1, fraction) {
+        Some(k + 1)
+    } else {
+        Some(k)
+    }
+}
+
+macro_rules! impl_roots {
+    (unsigned: $($t:ty),*) => {$(
+        impl Roots for $t {
+            #[inline]
+            fn nth_root_by<M: Rounding>(self, n: u32) -> Option<Self> {
+                let root = root_magnitude::<M>(u128::try_from(self).ok()?, n, false)?;
+                <$t>::try_from(root).ok()
+            }
+        }
+
+        impl ILog for $t {
+            #[inline]
+            fn ilog_by<M: Rounding>(self, base: Self) -> Option<u32> {
+                log::<M>(u128::try_from(self).ok()?, u128::try_from(base).ok()?)
+            }
+        }
+    )*};
+    (signed: $($t:ty => $u:ty),*) => {$(
+        impl Roots for $t {
+            #[inline]
+            fn nth_root_by<M: Rounding>(self, n: u32) -> Option<Self> {
+                let negative = self < 0;
+                if negative && n % 2 == 0 {
+                    return None;
+                }
+                let x = u128::try_from(self.unsigned_abs()).ok()?;
+                let root = <$u>::try_from(root_magnitude::<M>(x, n, negative)?).ok()?;
+                if negative {
+                    (0 as $t).checked_sub_unsigned(root)
+                } else {
+                    <$t>::try_from(root).ok()
+                }
+            }
+        }
+
+        impl ILog for $t {
+            #[inline]
+            fn ilog_by<M: Rounding>(self, base: Self) -> Option<u32> {
+                log::<M>(u128::try_from(self).ok()?, u128::try_from(base).ok()?)
+            }
+        }
+    )*};
+}
+
+impl_roots!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_roots!(signed: i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
@@ -0,0 +1,208 @@
+//! Rounding mode markers.
+//!
+//! Each marker type selects one of the division definitions of this crate
+//! at the type level, so that the mode can be a generic parameter:
+//!
+//! ```
+//! use divrem::rounding::{Ceil, Floor};
+//! use divrem::{DivBy, Rounding};
+//!
+//! fn bucket<M: Rounding>(x: i32, width: i32) -> i32
+//! where i32: DivBy<M> {
+//!     x.div_by(width)
+//! }
+//!
+//! assert_eq!(bucket::<Floor>(-7, 4), -2);
+//! assert_eq!(bucket::<Ceil>(-7, 4), -1);
+//! ```
+
+use core::ops::{Div, Rem};
+
+use crate::{
+    DivBy, DivCeil, DivEuclid, DivFloor, DivInt, DivRem, DivRemBy, DivRemCeil, DivRemEuclid,
+    DivRemFloor, RemBy, RemCeil, RemEuclid, RemFloor,
+};
+
+pub(crate) mod sealed {
+    use crate::DivInt;
+
+    /// The fractional part of the magnitude of an exact quotient.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum Fraction {
+        Zero,
+        BelowHalf,
+        Half,
+        AboveHalf,
+    }
+
+    impl Fraction {
+        /// Returns the fractional part of `n / d` from the remainder `r`.
+        ///
+        /// `r` and `d` are magnitudes with `r < d`.
+        #[inline]
+        pub fn new<T: DivInt>(r: T, d: T) -> Self {
+            if r == T::ZERO {
+                return Self::Zero;
+            }
+            Self::from_cmp(r.cmp(&(d - r)))
+        }
+
+        /// Returns the fractional part of a quotient from the comparison of
+        /// its non-zero remainder with the distance to the next multiple.
+        #[inline]
+        pub const fn from_cmp(ordering: core::cmp::Ordering) -> Self {
+            match ordering {
+                core::cmp::Ordering::Less => Self::BelowHalf,
+                core::cmp::Ordering::Equal => Self::Half,
+                core::cmp::Ordering::Greater => Self::AboveHalf,
+            }
+        }
+    }
+
+    /// The kind of a rounding mode, for implementations specialized by mode.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Mode {
+        Trunc,
+        Floor,
+        Ceil,
+        Euclid,
+        /// Rounding to the nearest integer, with any tie-breaking rule.
+        Nearest,
+    }
+
+    pub trait Sealed {
+        /// The kind of the rounding mode.
+        const MODE: Mode;
+
+        /// Returns whether the magnitude of the truncated quotient must be
+        /// incremented to round it.
+        ///
+        /// `negative` is the sign of the quotient, `dividend_negative` the
+        /// sign of the dividend and `odd` the parity of the truncated quotient.
+        fn round_up(negative: bool, dividend_negative: bool, odd: bool, fraction: Fraction)
+            -> bool;
+    }
+}
+
+use sealed::Fraction;
+
+/// Rounds the magnitude `q` of a truncated quotient with mode `M`.
+///
+/// `r` is the magnitude of the truncated remainder and `d` the magnitude of
+/// the divisor. Returns `None` if the rounded magnitude overflows.
+#[inline]
+pub(crate) fn round_magnitude<M: Rounding, T: DivInt>(
+    q: T,
+    r: T,
+    d: T,
+    negative: bool,
+    dividend_negative: bool,
+) -> Option<T> {
+    let odd = q % (T::ONE + T::ONE) != T::ZERO;
+    if M::round_up(negative, dividend_negative, odd, Fraction::new(r, d)) {
+        q.checked_add(T::ONE)
+    } else {
+        Some(q)
+    }
+}
+
+/// A rounding mode marker.
+///
+/// This trait is sealed and implemented by the marker types of this module.
+pub trait Rounding: sealed::Sealed {}
+
+macro_rules! rounding_marker {
+    (
+        $(#[$attr:meta])*
+        $name:ident($mode:ident) = |$negative:pat, $dividend_negative:pat, $odd:pat, $fraction:pat| $round_up:expr
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl sealed::Sealed for $name {
+            const MODE: sealed::Mode = sealed::Mode::$mode;
+
+            #[inline]
+            fn round_up($negative: bool, $dividend_negative: bool, $odd: bool, $fraction: Fraction) -> bool {
+                $round_up
+            }
+        }
+
+        impl Rounding for $name {}
+    };
+}
+
+rounding_marker! {
+    /// Truncated division, rounding towards zero.
+    ///
+    /// This is the same as the `/` and `%` operators.
+    Trunc(Trunc) = |_, _, _, _| false
+}
+
+rounding_marker! {
+    /// Floored division, rounding towards negative infinity.
+    Floor(Floor) = |negative, _, _, fraction| negative && fraction != Fraction::Zero
+}
+
+rounding_marker! {
+    /// Ceiled division, rounding towards positive infinity.
+    Ceil(Ceil) = |negative, _, _, fraction| !negative && fraction != Fraction::Zero
+}
+
+rounding_marker! {
+    /// Euclidean division, the modulus is always positive or zero.
+    Euclid(Euclid) = |_, dividend_negative, _, fraction| dividend_negative && fraction != Fraction::Zero
+}
+
+rounding_marker! {
+    /// Rounding to the nearest integer, with ties rounded away from zero.
+    ///
+    /// There are no division traits for this mode, it is used by the
+    /// functions taking a rounding mode parameter.
+    Round(Nearest) = |_, _, _, fraction| fraction >= Fraction::Half
+}
+
+rounding_marker! {
+    /// Rounding to the nearest integer, with ties rounded to the even
+    /// integer.
+    ///
+    /// This is the rounding of IEEE 754 arithmetic, which does not bias sums
+    /// of rounded values. Like [`Round`], it has no division traits.
+    HalfEven(Nearest) = |_, _, odd, fraction| fraction > Fraction::Half || (fraction == Fraction::Half && odd)
+}
+
+macro_rules! impl_by {
+    (
+        $mode:ident :
+        $div:ident($div_fn:ident),
+        $rem:ident($rem_fn:ident),
+        $divrem:ident($divrem_fn:ident)
+    ) => {
+        impl<T: $div<RHS>, RHS> DivBy<$mode, RHS> for T {
+            #[inline]
+            fn div_by(self, other: RHS) -> <Self as Div<RHS>>::Output {
+                $div::$div_fn(self, other)
+            }
+        }
+
+        impl<T: $rem<RHS>, RHS> RemBy<$mode, RHS> for T {
+            #[inline]
+            fn rem_by(self, other: RHS) -> <Self as Rem<RHS>>::Output {
+                $rem::$rem_fn(self, other)
+            }
+        }
+
+        impl<T: $divrem<RHS>, RHS> DivRemBy<$mode, RHS> for T {
+            #[inline]
+            fn div_rem_by(self, other: RHS) -> <Self as DivRem<RHS>>::Output {
+                $divrem::$divrem_fn(self, other)
+            }
+        }
+    };
+}
+
+impl_by!(Trunc: Div(div), Rem(rem), DivRem(div_rem));
+impl_by!(Floor: DivFloor(div_floor), RemFloor(rem_floor), DivRemFloor(div_rem_floor));
+impl_by!(Ceil: DivCeil(div_ceil), RemCeil(rem_ceil), DivRemCeil(div_rem_ceil));
+impl_by!(Euclid: DivEuclid(div_euclid), RemEuclid(rem_euclid), DivRemEuclid(div_rem_euclid));
@@ -1,24 +1,25 @@
-use core::num::Wrapping;
-use core::ops::{Div, Rem};
+use core::num::{Saturating, Wrapping};
 
+use super::int::{self, DivInt};
 use super::DivRem;
 
-macro_rules! impl_div_rem_trunc {
-    ($t:ty) => {
-        impl DivRem for $t {
-            type Output = (<Self as Div>::Output, <Self as Rem>::Output);
+impl<T: DivInt> DivRem for T {
+    type Output = (T, T);
 
-            #[inline]
-            fn div_rem(self, other: Self) -> <Self as DivRem>::Output {
-                (self / other, self % other)
-            }
-        }
+    #[inline]
+    fn div_rem(self, other: Self) -> (Self, Self) {
+        int::div_rem(self, other)
+    }
+}
 
+macro_rules! impl_div_rem_trunc {
+    ($t:ty) => {
         impl_forward_ref_binop!(impl DivRem<$t> for $t { div_rem -> Output });
     };
     ($($t:ty),*) => {$(
         impl_div_rem_trunc!($t);
         impl_div_rem_trunc!(Wrapping<$t>);
+        impl_div_rem_trunc!(Saturating<$t>);
     )*};
 }
 
@@ -0,0 +1,197 @@
+//! Double-width arithmetic on primitive integers.
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+
+use crate::TryDivRem;
+
+const HALF_BITS: u32 = 64;
+const LOW_MASK: u128 = (1 << HALF_BITS) - 1;
+
+/// Returns the 256-bit product of `a` and `b` as `(high, low)` words.
+#[inline]
+pub const fn mul_u128(a: u128, b: u128) -> (u128, u128) {
+    let (a1, a0) = (a >> HALF_BITS, a & LOW_MASK);
+    let (b1, b0) = (b >> HALF_BITS, b & LOW_MASK);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    // Sum of three values below 2⁶⁴, no overflow.
+    let mid = (p00 >> HALF_BITS) + (p01 & LOW_MASK) + (p10 & LOW_MASK);
+    let low = (p00 & LOW_MASK) | (mid << HALF_BITS);
+    let high = p11 + (p01 >> HALF_BITS) + (p10 >> HALF_BITS) + (mid >> HALF_BITS);
+    (high, low)
+}
+
+/// Divides the 256-bit number `(high, low)` by `d`.
+///
+/// Returns the quotient and remainder. The quotient must fit in 128 bits,
+/// that is `high < d`.
+///
+/// This is algorithm `divlu` from Hacker's Delight, with 64-bit digits.
+pub fn div_rem_u256(high: u128, low: u128, d: u128) -> (u128, u128) {
+    const B: u128 = 1 << HALF_BITS;
+
+    debug_assert!(high < d);
+    // Normalize the divisor so that its most significant bit is set.
+    let s = d.leading_zeros();
+    let d = d << s;
+    let (d1, d0) = (d >> HALF_BITS, d & LOW_MASK);
+    let n32 = if s == 0 { high } else { (high << s) | (low >> (128 - s)) };
+    let n10 = low << s;
+    let (n1, n0) = (n10 >> HALF_BITS, n10 & LOW_MASK);
+
+    let mut q1 = n32 / d1;
+    let mut rhat = n32 % d1;
+    while q1 >= B || q1 * d0 > B * rhat + n1 {
+        q1 -= 1;
+        rhat += d1;
+        if rhat >= B {
+            break;
+        }
+    }
+    // The exact value is below `d`, the wrapping operations are exact.
+    let n21 = n32.wrapping_mul(B).wrapping_add(n1).wrapping_sub(q1.wrapping_mul(d));
+
+    let mut q0 = n21 / d1;
+    let mut rhat = n21 % d1;
+    while q0 >= B || q0 * d0 > B * rhat + n0 {
+        q0 -= 1;
+        rhat += d1;
+        if rhat >= B {
+            break;
+        }
+    }
+    let r = n21.wrapping_mul(B).wrapping_add(n0).wrapping_sub(q0.wrapping_mul(d));
+
+    (q1 * B + q0, r >> s)
+}
+
+/// A 512-bit unsigned integer, wide enough to compare exactly the powers
+/// that round roots and logarithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct U512([u64; 8]);
+
+impl U512 {
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)] // Splitting into words.
+    pub const fn new(x: u128) -> Self {
+        Self([x as u64, (x >> HALF_BITS) as u64, 0, 0, 0, 0, 0, 0])
+    }
+
+    /// Multiplies by `c`, or returns `None` on overflow.
+    #[allow(clippy::cast_possible_truncation)] // Splitting into words.
+    pub fn checked_mul(self, c: u128) -> Option<Self> {
+        let c = [c as u64, (c >> HALF_BITS) as u64];
+        let mut product = [0_u64; 10];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in c.iter().enumerate() {
+                let sum = u128::from(a) * u128::from(b) + u128::from(product[i + j]) + carry;
+                product[i + j] = sum as u64;
+                carry = sum >> HALF_BITS;
+            }
+            product[i + 2] = carry as u64;
+        }
+        if product[8] != 0 || product[9] != 0 {
+            return None;
+        }
+        let mut limbs = [0; 8];
+        limbs.copy_from_slice(&product[..8]);
+        Some(Self(limbs))
+    }
+
+    /// Multiplies by `2ⁿ`, or returns `None` on overflow.
+    pub fn checked_shl(self, n: u32) -> Option<Self> {
+        let mut x = self;
+        for _ in 0..n / HALF_BITS {
+            x = x.checked_mul(1 << HALF_BITS)?;
+        }
+        x.checked_mul(1 << (n % HALF_BITS))
+    }
+}
+
+impl Ord for U512 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U512 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Division of a double-word number by a single word.
+///
+/// The dividend is `high * 2ᴺ + low` where `N` is the bit width of the type.
+/// For signed types, `high` is signed and `low` is its unsigned counterpart,
+/// as in a two's complement double-word number.
+///
+/// Every method returns the quotient and remainder, or `None` if the divisor
+/// is zero or the quotient does not fit in the type.
+///
+/// This trait is implemented for the 8 to 64-bit integer types.
+pub trait DivRemWide: Sized {
+    /// The type of the low word of the dividend.
+    type Low;
+
+    /// Performs the truncated division with remainder of `(high, low)`
+    /// by `other`. See [`DivRem`](crate::DivRem).
+    fn div_rem_wide(high: Self, low: Self::Low, other: Self) -> Option<(Self, Self)>;
+
+    /// Performs the floored division with remainder of `(high, low)`
+    /// by `other`. See [`DivRemFloor`](crate::DivRemFloor).
+    fn div_rem_wide_floor(high: Self, low: Self::Low, other: Self) -> Option<(Self, Self)>;
+
+    /// Performs the euclidean division with remainder of `(high, low)`
+    /// by `other`. See [`DivRemEuclid`](crate::DivRemEuclid).
+    fn div_rem_wide_euclid(high: Self, low: Self::Low, other: Self) -> Option<(Self, Self)>;
+}
+
+/// Ceiled division of a double-word number by a single word.
+///
+/// Like `DivRemCeil`, this is not implemented for unsigned types since the
+/// ceiled modulus is negative or zero.
+pub trait DivRemWideCeil: DivRemWide {
+    /// Performs the ceiled division with remainder of `(high, low)`
+    /// by `other`. See [`DivRemCeil`](crate::DivRemCeil).
+    fn div_rem_wide_ceil(high: Self, low: Self::Low, other: Self) -> Option<(Self, Self)>;
+}
+
+macro_rules! div_rem_wide_fn {
+    ($t:ty, $low:ty, $w:ty, $method:ident, $try_method:ident) => {
+        #[inline]
+        fn $method(high: Self, low: $low, other: Self) -> Option<(Self, Self)> {
+            let n = (<$w>::from(high) << <$t>::BITS) | <$w>::from(low);
+            let (q, r) = TryDivRem::$try_method(n, <$w>::from(other)).ok()?;
+            Some((<$t>::try_from(q).ok()?, <$t>::try_from(r).ok()?))
+        }
+    };
+}
+
+macro_rules! impl_div_rem_wide {
+    ($t:ty, $low:ty => $w:ty) => {
+        impl DivRemWide for $t {
+            type Low = $low;
+
+            div_rem_wide_fn!($t, $low, $w, div_rem_wide, try_div_rem);
+            div_rem_wide_fn!($t, $low, $w, div_rem_wide_floor, try_div_rem_floor);
+            div_rem_wide_fn!($t, $low, $w, div_rem_wide_euclid, try_div_rem_euclid);
+        }
+    };
+    (unsigned: $($t:ty => $w:ty),*) => {$(
+        impl_div_rem_wide!($t, $t => $w);
+    )*};
+    (signed: $($t:ty, $low:ty => $w:ty),*) => {$(
+        impl_div_rem_wide!($t, $low => $w);
+
+        impl DivRemWideCeil for $t {
+            div_rem_wide_fn!($t, $low, $w, div_rem_wide_ceil, try_div_rem_ceil);
+        }
+    )*};
+}
+
+impl_div_rem_wide!(unsigned: u8 => u16, u16 => u32, u32 => u64, u64 => u128);
+impl_div_rem_wide!(signed: i8, u8 => i16, i16, u16 => i32, i32, u32 => i64, i64, u64 => i128);
@@ -0,0 +1,95 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::num::Wrapping;
+
+use divrem::prelude::*;
+use divrem::{Checked, DivError};
+
+#[test]
+fn test_ok() {
+    for x in -32_i32..32 {
+        for y in (-8_i32..8).filter(|&y| y != 0) {
+            assert_eq!(x.try_div(y), Ok(x / y));
+            assert_eq!(x.try_rem(y), Ok(x % y));
+            assert_eq!(x.try_div_rem(y), Ok(x.div_rem(y)));
+            assert_eq!(x.try_div_floor(y), Ok(x.floor_div(y)));
+            assert_eq!(x.try_rem_floor(y), Ok(x.floor_rem(y)));
+            assert_eq!(x.try_div_rem_floor(y), Ok(x.floor_div_rem(y)));
+            assert_eq!(x.try_div_ceil(y), Ok(x.ceil_div(y)));
+            assert_eq!(x.try_rem_ceil(y), Ok(x.ceil_rem(y)));
+            assert_eq!(x.try_div_rem_ceil(y), Ok(x.ceil_div_rem(y)));
+            assert_eq!(x.try_div_euclid(y), Ok(x.euclid_div(y)));
+            assert_eq!(x.try_rem_euclid(y), Ok(x.euclid_rem(y)));
+            assert_eq!(x.try_div_rem_euclid(y), Ok(x.euclid_div_rem(y)));
+        }
+    }
+}
+
+#[test]
+fn test_division_by_zero() {
+    assert_eq!(7_u8.try_div_ceil(0), Err(DivError::DivisionByZero));
+    assert_eq!((-7_i64).try_rem_euclid(0), Err(DivError::DivisionByZero));
+    assert_eq!(Wrapping(7_i8).try_div_rem_floor(Wrapping(0)), Err(DivError::DivisionByZero));
+}
+
+#[test]
+fn test_overflow() {
+    assert_eq!(i32::MIN.try_div_floor(-1), Err(DivError::Overflow));
+    assert_eq!(i32::MIN.try_rem_ceil(-1), Err(DivError::Overflow));
+    assert_eq!(i128::MIN.try_div_rem_euclid(-1), Err(DivError::Overflow));
+    assert_eq!(Wrapping(i8::MIN).try_div(Wrapping(-1)), Err(DivError::Overflow));
+    assert_eq!(i32::MIN.try_div_floor(1), Ok(i32::MIN));
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(DivError::DivisionByZero.to_string(), "division by zero");
+    assert_eq!(DivError::Overflow.to_string(), "quotient overflow");
+}
+
+#[test]
+fn test_checked_ok() {
+    for x in -32_i32..32 {
+        for y in (-8_i32..8).filter(|&y| y != 0) {
+            let (cx, cy) = (Checked::new(x), Checked::new(y));
+            let pair = |(q, r): (i32, i32)| (Checked::new(q), Checked::new(r));
+            assert_eq!((cx / cy).get(), Some(x / y));
+            assert_eq!((cx % cy).get(), Some(x % y));
+            assert_eq!(cx.div_rem(cy), pair(x.div_rem(y)));
+            assert_eq!(cx.div_floor(cy).get(), Some(x.floor_div(y)));
+            assert_eq!(cx.rem_floor(cy).get(), Some(x.floor_rem(y)));
+            assert_eq!(cx.div_rem_floor(cy), pair(x.floor_div_rem(y)));
+            assert_eq!(cx.div_ceil(cy).get(), Some(x.ceil_div(y)));
+            assert_eq!(cx.rem_ceil(cy).get(), Some(x.ceil_rem(y)));
+            assert_eq!(cx.div_rem_ceil(cy), pair(x.ceil_div_rem(y)));
+            assert_eq!(cx.div_euclid(cy).get(), Some(x.euclid_div(y)));
+            assert_eq!(cx.rem_euclid(cy).get(), Some(x.euclid_rem(y)));
+            assert_eq!(cx.div_rem_euclid(cy), pair(x.euclid_div_rem(y)));
+            assert_eq!((&cx).div_rem(&cy), cx.div_rem(cy));
+            assert_eq!((&cx).div_floor(cy), cx.div_floor(&cy));
+        }
+    }
+}
+
+#[test]
+fn test_checked_poison() {
+    let (min, minus_one) = (Checked::new(i32::MIN), Checked::new(-1));
+    assert!((min / minus_one).is_poisoned());
+    assert!(min.rem_ceil(minus_one).is_poisoned());
+    assert_eq!(min.div_rem_euclid(minus_one), (Checked::poisoned(), Checked::poisoned()));
+    assert_eq!(min.div_floor(Checked::new(1)).get(), Some(i32::MIN));
+
+    let x = Checked::from(100_u8);
+    let poisoned = x.div_floor(Checked::new(0));
+    assert!(poisoned.is_poisoned());
+    assert_eq!((poisoned / Checked::new(1)).get(), None);
+    assert_eq!((x / poisoned).get(), None);
+    assert_eq!((x / Checked::new(7) % Checked::new(5)).get(), Some(4));
+
+    let x = Checked::new(Wrapping(i8::MIN));
+    assert!(x.div_euclid(Checked::new(Wrapping(-1))).is_poisoned());
+}
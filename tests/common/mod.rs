@@ -0,0 +1,11 @@
+use divrem::DivRem;
+
+/// Reference `n / d` rounded to nearest, ties away from zero.
+pub fn div_round(n: i128, d: i128) -> i128 {
+    let (q, r) = n.div_rem(d);
+    if 2 * r.abs() >= d.abs() {
+        q + n.signum() * d.signum()
+    } else {
+        q
+    }
+}
@@ -0,0 +1,85 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+
+use divrem::congruence::{crt, solve_linear_congruence};
+
+#[test]
+fn test_crt_small() {
+    for m1 in 1..=12_i32 {
+        for m2 in 1..=12 {
+            let lcm = (1..=m1 * m2).find(|x| x % m1 == 0 && x % m2 == 0).unwrap();
+            for r1 in -15..15 {
+                for r2 in -15..15 {
+                    let expected = (0..lcm)
+                        .find(|x| (x - r1).rem_euclid(m1) == 0 && (x - r2).rem_euclid(m2) == 0)
+                        .map(|x| (x, lcm));
+                    assert_eq!(
+                        crt(&[(r1, m1), (r2, m2)]),
+                        expected,
+                        "{r1} mod {m1}, {r2} mod {m2}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_crt() {
+    assert_eq!(crt::<i32>(&[]), Some((0, 1)));
+    assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt(&[(1, 4), (3, 6), (7, 10)]), Some((57, 60)));
+    assert_eq!(crt(&[(1, 0)]), None);
+    assert_eq!(crt(&[(1, -3)]), None);
+    assert_eq!(crt(&[(1_u8, 16), (2, 17)]), None);
+    assert_eq!(crt(&[(1_u8, 15), (2, 17)]), Some((121, 255)));
+    // The intermediate products overflow, the result does not.
+    let (p, q) = (u64::MAX - 58, (1 << 61) - 1); // Primes.
+    assert_eq!(crt(&[(p - 1, p), (5, q)]), None);
+    let (x, m) = crt(&[(u128::from(p) - 1, u128::from(p)), (5, u128::from(q))]).unwrap();
+    assert_eq!(
+        (x % u128::from(p), x % u128::from(q), m),
+        (u128::from(p) - 1, 5, u128::from(p) * u128::from(q))
+    );
+    assert_eq!(crt(&[(i128::MAX - 1, i128::MAX), (0, 2)]), None);
+    let p127 = (1_u128 << 127) - 1;
+    assert_eq!(crt(&[(0, 2), (5, p127)]), Some(((1 << 127) + 4, u128::MAX - 1)));
+    assert_eq!(crt(&[(i64::MIN + 3, 1 << 40), (3, 1 << 20)]), Some((3, 1 << 40)));
+}
+
+#[test]
+fn test_linear_congruence() {
+    for m in -3..=30_i32 {
+        for a in -35..35 {
+            for b in -35..35 {
+                let expected: Vec<_> = (0..m).filter(|x| (a * x - b).rem_euclid(m) == 0).collect();
+                let solutions = solve_linear_congruence(a, b, m);
+                let Some(solutions) = solutions else {
+                    assert!(expected.is_empty(), "{} * x = {} mod {}", a, b, m);
+                    continue;
+                };
+                assert_eq!(solutions.residue(), expected[0], "{a} * x = {b} mod {m}");
+                assert_eq!(solutions.modulus(), m / i32::try_from(expected.len()).unwrap());
+                assert_eq!(solutions.collect::<Vec<_>>(), expected, "{a} * x = {b} mod {m}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_linear_congruence_wide() {
+    let mut solutions = solve_linear_congruence(u128::MAX - 1, 1, u128::MAX).unwrap();
+    assert_eq!((solutions.residue(), solutions.modulus()), (u128::MAX - 1, u128::MAX));
+    assert_eq!((solutions.next(), solutions.next()), (Some(u128::MAX - 1), None));
+    let solutions = solve_linear_congruence(i128::MIN, 0, i128::MAX).unwrap();
+    assert_eq!((solutions.residue(), solutions.modulus()), (0, i128::MAX));
+    let solutions = solve_linear_congruence(2_u8, 4, 254).unwrap();
+    assert!(solutions.eq([2, 129]));
+    assert!(solve_linear_congruence(0_u8, 0, 255).unwrap().eq(0..255));
+}
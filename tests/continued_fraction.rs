@@ -0,0 +1,88 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::cmp::Ordering;
+
+use divrem::continued_fraction::{best_approximation, convergents, semiconvergents, terms};
+use divrem::{DivFloor, Gcd};
+
+/// Compares `|p / q - a / b|` and `|p / q - c / d|` for positive `b` and `d`.
+fn cmp_distances(p: i64, q: i64, first: (i64, i64), second: (i64, i64)) -> Ordering {
+    let distance = |(num, den): (i64, i64)| i128::from(p * den - num * q).abs();
+    (distance(first) * i128::from(second.1)).cmp(&(distance(second) * i128::from(first.1)))
+}
+
+/// Reference best approximation by exhaustive search.
+fn best(p: i64, q: i64, max_den: i64) -> (i64, i64) {
+    let mut best = (DivFloor::div_floor(p, q), 1);
+    for k in 1..=max_den {
+        let h = DivFloor::div_floor(p * k, q);
+        for h in [h, h + 1] {
+            if cmp_distances(p, q, (h, k), best) == Ordering::Less {
+                best = (h, k);
+            }
+        }
+    }
+    best
+}
+
+#[test]
+fn test_small() {
+    for p in -60..=60_i64 {
+        for q in (-30..=30).filter(|&q| q != 0) {
+            let terms: Vec<_> = terms(p, q).collect();
+            assert!(terms.iter().skip(1).all(|&a| a > 0), "{} / {}: {:?}", p, q, terms);
+            let convergents: Vec<_> = convergents(p, q).collect();
+            assert_eq!(convergents.len(), terms.len());
+            let &(h, k) = convergents.last().unwrap();
+            assert_eq!((h * q, h.gcd(k)), (p * k, 1), "{p} / {q}");
+            let semiconvergents: Vec<_> = semiconvergents(p, q).collect();
+            assert!(semiconvergents
+                .iter()
+                .skip(1)
+                .zip(semiconvergents.iter().skip(2))
+                .all(|(a, b)| a.1 < b.1));
+            assert!(convergents.iter().all(|c| semiconvergents.contains(c)), "{} / {}", p, q);
+            for max_den in 1..=35 {
+                let expected = best(p, q, max_den);
+                assert_eq!(
+                    best_approximation(p, q, max_den),
+                    Some(expected),
+                    "{p} / {q}, {max_den}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pi() {
+    let (p, q) = (314_159_265_358_979_i64, 100_000_000_000_000);
+    assert!(terms(p, q).take(5).eq([3, 7, 15, 1, 292]));
+    assert_eq!(best_approximation(p, q, 100), Some((311, 99)));
+    assert_eq!(best_approximation(p, q, 1000), Some((355, 113)));
+    assert_eq!(best_approximation(-p, q, 1000), Some((-355, 113)));
+    assert_eq!(best_approximation(p, -q, 1000), Some((-355, 113)));
+    assert_eq!(semiconvergents(355, 113).count(), 1 + 7 + 16);
+}
+
+#[test]
+fn test_extremes() {
+    assert_eq!(terms(i32::MIN, -1).next(), None);
+    assert!(terms(-1, i32::MIN).eq([0]));
+    assert!(terms(1, 0).eq([]));
+    assert!(convergents(u64::MAX, u64::MAX - 1).eq([(1, 1), (u64::MAX, u64::MAX - 1)]));
+    assert!(convergents(i64::MAX, 1).eq([(i64::MAX, 1)]));
+    assert_eq!(convergents(i64::MIN + 1, 2).count(), 2);
+    assert_eq!(semiconvergents(i8::MAX, 2).last(), Some((i8::MAX, 2)));
+    assert_eq!(best_approximation(i64::MAX, i64::MIN, 10), Some((-1, 1)));
+    assert_eq!(
+        best_approximation(u128::MAX - 1, u128::MAX, u128::MAX - 2),
+        Some((u128::MAX - 3, u128::MAX - 2))
+    );
+    assert_eq!(best_approximation(1, 0, 10), None);
+    assert_eq!(best_approximation(1, 2, 0), None);
+}
@@ -0,0 +1,372 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+use std::num::Wrapping;
+
+use divrem::decimal::{
+    expand, parse_scaled_by, parse_scaled_exact, Expansion, ParseDecimalError, ScaledDecimal,
+};
+use divrem::rounding::{Ceil, Euclid, Floor, HalfEven, Round, Trunc};
+use divrem::{MulDiv, Rounding};
+
+/// Reference long division of `|a| / |b|`, returning the non-repeating
+/// fractional digits and the cycle.
+fn long_division(a: i128, b: i128) -> (Vec<u8>, Vec<u8>) {
+    let (a, b) = (a.abs(), b.abs());
+    let (mut remainders, mut digits) = (Vec::new(), Vec::new());
+    let mut r = a % b;
+    while !remainders.contains(&r) {
+        remainders.push(r);
+        digits.push(u8::try_from(r * 10 / b).unwrap());
+        r = r * 10 % b;
+    }
+    let start = remainders.iter().position(|&x| x == r).unwrap();
+    let cycle = digits.split_off(start);
+    if cycle == [0] {
+        (digits, Vec::new())
+    } else {
+        (digits, cycle)
+    }
+}
+
+/// Reference rounded expansion from `a * 10^n / b` rounded with `M`.
+fn rounded<M: Rounding>(a: i128, b: i128, n: u32) -> (bool, i128, Vec<u8>) {
+    let scale = 10_i128.pow(n);
+    let scaled = a.mul_div_by::<M>(scale, b).unwrap();
+    let fraction = (scaled % scale).abs();
+    let digits = (0..n).rev().map(|i| u8::try_from(fraction / 10_i128.pow(i) % 10).unwrap());
+    (scaled < 0, scaled / scale, digits.collect())
+}
+
+fn check_rounded<M: Rounding, T>(expansion: &Expansion<T>, a: i128, b: i128)
+where
+    T: divrem::DivInt + std::fmt::Debug,
+    i128: TryFrom<T>,
+    <i128 as TryFrom<T>>::Error: std::fmt::Debug,
+{
+    for n in 0..=4 {
+        let (negative, integer, digits) = rounded::<M>(a, b, n);
+        let n = usize::try_from(n).unwrap();
+        let rounded = expansion.rounded_by::<M>(n);
+        assert_eq!(rounded.len(), n);
+        let args = format!("{a}/{b} to {n} digits");
+        assert_eq!(rounded.is_negative(), negative, "{args}");
+        assert_eq!(i128::try_from(rounded.integer()).unwrap(), integer, "{args}");
+        assert_eq!(rounded.collect::<Vec<_>>(), digits, "{args}");
+    }
+}
+
+macro_rules! test_exhaustive {
+    ($test_name:ident, $t:ident) => {
+        #[test]
+        fn $test_name() {
+            for a in $t::MIN..=$t::MAX {
+                for b in $t::MIN..=$t::MAX {
+                    let (wide_a, wide_b) = (i128::from(a), i128::from(b));
+                    let Some(expansion) = expand(a, b) else {
+                        assert!(b == 0 || $t::try_from(wide_a / wide_b).is_err());
+                        continue;
+                    };
+                    let args = format!("{a}/{b}");
+                    assert_eq!(expansion.is_negative(), wide_a * wide_b < 0, "{args}");
+                    assert_eq!(i128::from(expansion.integer()), wide_a / wide_b, "{args}");
+                    let (non_repeating, repeating) = long_division(wide_a, wide_b);
+                    assert_eq!(
+                        expansion.non_repeating().collect::<Vec<_>>(),
+                        non_repeating,
+                        "{args}"
+                    );
+                    assert_eq!(expansion.repeating().collect::<Vec<_>>(), repeating, "{args}");
+                    check_rounded::<Trunc, _>(&expansion, wide_a, wide_b);
+                    check_rounded::<Floor, _>(&expansion, wide_a, wide_b);
+                    check_rounded::<Ceil, _>(&expansion, wide_a, wide_b);
+                    check_rounded::<Euclid, _>(&expansion, wide_a, wide_b);
+                    check_rounded::<Round, _>(&expansion, wide_a, wide_b);
+                }
+            }
+        }
+    };
+}
+
+test_exhaustive!(test_i8, i8);
+test_exhaustive!(test_u8, u8);
+
+#[test]
+fn test_display() {
+    let display = |a: i32, b: i32| expand(a, b).unwrap().to_string();
+    assert_eq!(display(1, 7), "0.(142857)");
+    assert_eq!(display(-1, 7), "-0.(142857)");
+    assert_eq!(display(1, -7), "-0.(142857)");
+    assert_eq!(display(-1, -7), "0.(142857)");
+    assert_eq!(display(22, 7), "3.(142857)");
+    assert_eq!(display(-22, 7), "-3.(142857)");
+    assert_eq!(display(1, 6), "0.1(6)");
+    assert_eq!(display(1, 3), "0.(3)");
+    assert_eq!(display(1, 8), "0.125");
+    assert_eq!(display(10, 5), "2");
+    assert_eq!(display(0, -5), "0");
+    assert_eq!(display(1, 81), "0.(012345679)");
+    assert_eq!(display(i32::MIN, 1), "-2147483648");
+    assert_eq!(display(-1, i32::MIN), "0.0000000004656612873077392578125");
+    assert_eq!(display(1, i32::MIN), "-0.0000000004656612873077392578125");
+    assert_eq!(expand(Wrapping(-1_i8), Wrapping(3)).unwrap().to_string(), "-0.(3)");
+}
+
+#[test]
+fn test_extremes() {
+    assert!(expand(1, 0).is_none());
+    assert!(expand(i64::MIN, -1).is_none());
+    // The remainders are close to the limits of the type.
+    let expansion = expand(u128::MAX - 1, u128::MAX).unwrap();
+    assert_eq!(expansion.integer(), 0);
+    // `u128::MAX` is a multiple of 5.
+    assert!(expansion.non_repeating().eq([9]));
+    assert!(expansion.repeating().take(6).eq([9, 9, 9, 9, 9, 9]));
+    let expansion = expand(i128::MAX - 1, i128::MIN).unwrap();
+    assert!(expansion.is_negative());
+    assert_eq!(expansion.non_repeating().count(), 126);
+    assert_eq!(expansion.repeating().count(), 0);
+    assert!(expansion.non_repeating().take(4).eq([9, 9, 9, 9]));
+    let rounded = expansion.rounded_by::<Round>(10);
+    assert_eq!((rounded.is_negative(), rounded.integer()), (true, -1));
+    assert!(rounded.eq([0; 10]));
+    let rounded = expansion.rounded_by::<Ceil>(10);
+    assert_eq!((rounded.is_negative(), rounded.integer()), (true, 0));
+    assert!(rounded.eq([9; 10]));
+    assert_eq!(expand(i8::MAX, 2).unwrap().rounded_by::<Ceil>(0).integer(), 64);
+    assert_eq!(expand(i8::MIN + 1, 2).unwrap().rounded_by::<Floor>(0).integer(), -64);
+    assert_eq!(expand(i8::MIN, 1).unwrap().rounded_by::<Floor>(2).integer(), i8::MIN);
+    let rounded = expand(-1, i8::MIN).unwrap().rounded_by::<Floor>(3);
+    assert!(!rounded.is_negative() && rounded.eq([0, 0, 7]));
+    let rounded = expand(1, i8::MIN).unwrap().rounded_by::<Floor>(3);
+    assert!(rounded.is_negative() && rounded.eq([0, 0, 8]));
+    // A long cycle is produced lazily.
+    let expansion = expand(1, 1_000_000_007_u64).unwrap();
+    assert!(expansion.repeating().take(12).eq([0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9]));
+}
+
+/// Reference formatting of `value / 10^scale`, dividing by
+/// `10^(scale - digits)` with rounding mode `M` or padding with zeros.
+fn scaled<M: Rounding>(value: i128, scale: u32, digits: u32) -> String {
+    let (q, shown) = if digits <= scale {
+        (value.mul_div_by::<M>(1, 10_i128.pow(scale - digits)).unwrap(), digits)
+    } else {
+        (value, scale)
+    };
+    let shown = usize::try_from(shown).unwrap();
+    let magnitude = format!("{:0width$}", q.unsigned_abs(), width = shown + 1);
+    let (integer, fraction) = magnitude.split_at(magnitude.len() - shown);
+    let sign = if q < 0 { "-" } else { "" };
+    let point = if digits == 0 { "" } else { "." };
+    let zeros = "0".repeat(usize::try_from(digits.saturating_sub(scale)).unwrap());
+    format!("{sign}{integer}{point}{fraction}{zeros}")
+}
+
+fn check_scaled<T: divrem::DivInt + Into<i128>>(value: T, scales: std::ops::RangeInclusive<u32>) {
+    for scale in scales {
+        for digits in 0..=scale + 2 {
+            let args = format!("{} / 10^{scale} to {digits} digits", value.into());
+            let wide = value.into();
+            macro_rules! check {
+                ($($mode:ident),*) => {$(
+                    let decimal = ScaledDecimal { value, scale, digits, mode: $mode };
+                    let expected = scaled::<$mode>(wide, scale, digits);
+                    assert_eq!(decimal.to_string(), expected, "{} {args}", stringify!($mode));
+                )*};
+            }
+            check!(Trunc, Floor, Ceil, Euclid, Round, HalfEven);
+        }
+    }
+}
+
+#[test]
+fn test_scaled_small() {
+    for value in i8::MIN..=i8::MAX {
+        check_scaled(value, 0..=4);
+    }
+    for value in u8::MIN..=u8::MAX {
+        check_scaled(value, 0..=4);
+    }
+}
+
+#[test]
+fn test_scaled_large() {
+    for value in [i64::MIN, i64::MIN + 1, -5_000_000_000_000_000, -1, 0, 1, 15, 25, i64::MAX] {
+        check_scaled(value, 0..=21);
+    }
+    for value in [0, 5, 5_000_000_000_000_000_000, u64::MAX] {
+        check_scaled(value, 0..=21);
+    }
+    for value in [i128::MIN, -(10_i128.pow(37) * 5), -1, 1, 10_i128.pow(37) * 5, i128::MAX] {
+        check_scaled(value, 0..=36);
+    }
+}
+
+#[test]
+fn test_scaled_examples() {
+    let decimal = ScaledDecimal { value: 21_450, scale: 3, digits: 1, mode: HalfEven };
+    assert_eq!(decimal.to_string(), "21.4");
+    assert_eq!(ScaledDecimal { value: 21_350, ..decimal }.to_string(), "21.4");
+    assert_eq!(ScaledDecimal { value: 21_351, ..decimal }.to_string(), "21.4");
+    assert_eq!(ScaledDecimal { value: -21_449, ..decimal }.to_string(), "-21.4");
+    assert_eq!(ScaledDecimal { value: -49, ..decimal }.to_string(), "0.0");
+    assert_eq!(ScaledDecimal { value: -50, ..decimal }.to_string(), "0.0");
+    assert_eq!(ScaledDecimal { value: -51, ..decimal }.to_string(), "-0.1");
+    assert_eq!(ScaledDecimal { value: 7, digits: 5, ..decimal }.to_string(), "0.00700");
+    let decimal = ScaledDecimal { value: 7_u8, scale: 0, digits: 2, mode: Floor };
+    assert_eq!(decimal.to_string(), "7.00");
+    let decimal = ScaledDecimal { value: Wrapping(-7_i32), scale: 1, digits: 0, mode: Round };
+    assert_eq!(decimal.to_string(), "-1");
+    let decimal = ScaledDecimal { value: i32::MIN, scale: 40, digits: 40, mode: Round };
+    assert_eq!(decimal.to_string(), "-0.0000000000000000000000000000002147483648");
+    assert_eq!(
+        ScaledDecimal { digits: 31, ..decimal }.to_string(),
+        "-0.0000000000000000000000000000002"
+    );
+    assert_eq!(
+        ScaledDecimal { digits: 30, ..decimal }.to_string(),
+        "0.000000000000000000000000000000"
+    );
+}
+
+/// A writer failing after `capacity` bytes, to write the start of huge
+/// numbers.
+struct Truncated {
+    buffer: String,
+    capacity: usize,
+}
+
+impl std::fmt::Write for Truncated {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if self.buffer.len() + s.len() > self.capacity {
+            return Err(std::fmt::Error);
+        }
+        self.buffer.push_str(s);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_scaled_huge() {
+    use std::fmt::Write;
+
+    let start = |decimal: ScaledDecimal<i32, Trunc>| {
+        let mut writer = Truncated { buffer: String::new(), capacity: 8 };
+        assert!(write!(writer, "{decimal}").is_err());
+        writer.buffer
+    };
+    let decimal = ScaledDecimal { value: -7, scale: u32::MAX, digits: u32::MAX, mode: Trunc };
+    assert_eq!(start(decimal), "-0.00000");
+    assert_eq!(start(ScaledDecimal { scale: 0, ..decimal }), "-7.00000");
+    assert_eq!(start(ScaledDecimal { digits: u32::MAX - 1, ..decimal }), "0.000000");
+}
+
+/// Reference parse of `n / 1000` at `scale`, rounded with `M`.
+fn parsed<M: Rounding, T: TryFrom<i128>>(n: i128, scale: u32) -> Result<T, ParseDecimalError> {
+    let scaled = n.mul_div_by::<M>(10_i128.pow(scale), 1000).unwrap();
+    T::try_from(scaled).map_err(|_| ParseDecimalError::Overflow)
+}
+
+fn check_parse<M: Rounding, T>(s: &str, n: i128, scale: u32)
+where T: divrem::DivInt + TryFrom<i128> + std::fmt::Debug {
+    let expected = parsed::<M, T>(n, scale);
+    assert_eq!(parse_scaled_by::<M, T>(s, scale), expected, "{s} at scale {scale}");
+}
+
+macro_rules! test_parse {
+    ($test_name:ident, $t:ident) => {
+        #[test]
+        fn $test_name() {
+            for n in (-40_000_000_i128..=40_000_000).step_by(9973).chain(-2000..=2000) {
+                // `n / 1000` written with three fractional digits.
+                let sign = if n < 0 { "-" } else { "" };
+                let (integer, fraction) = (n.abs() / 1000, n.abs() % 1000);
+                let s = format!("{sign}{integer}.{fraction:03}");
+                for scale in 0..=6 {
+                    check_parse::<Trunc, $t>(&s, n, scale);
+                    check_parse::<Floor, $t>(&s, n, scale);
+                    check_parse::<Ceil, $t>(&s, n, scale);
+                    check_parse::<Euclid, $t>(&s, n, scale);
+                    check_parse::<Round, $t>(&s, n, scale);
+                    check_parse::<HalfEven, $t>(&s, n, scale);
+                    let exact = match parsed::<Trunc, $t>(n, scale) {
+                        Ok(_) if n * 10_i128.pow(scale) % 1000 != 0 => {
+                            Err(ParseDecimalError::Inexact)
+                        }
+                        result => result,
+                    };
+                    assert_eq!(parse_scaled_exact::<$t>(&s, scale), exact, "{s} at scale {scale}");
+                }
+            }
+        }
+    };
+}
+
+test_parse!(test_parse_i8, i8);
+test_parse!(test_parse_u8, u8);
+test_parse!(test_parse_i16, i16);
+
+#[test]
+fn test_parse_round_trip() {
+    for value in i8::MIN..=i8::MAX {
+        for scale in 0..=5 {
+            let s = ScaledDecimal { value, scale, digits: scale, mode: Trunc }.to_string();
+            assert_eq!(parse_scaled_exact(&s, scale), Ok(value), "{s}");
+            let s = ScaledDecimal { value, scale, digits: scale + 3, mode: Trunc }.to_string();
+            assert_eq!(parse_scaled_exact(&s, scale), Ok(value), "{s}");
+        }
+    }
+}
+
+#[test]
+fn test_parse_syntax() {
+    for s in
+        ["", "-", "+", ".", "-.", "1.2.3", "+-1", "--1", " 1", "1 ", "1e3", "1_000", "0x10", "١"]
+    {
+        assert_eq!(
+            parse_scaled_by::<Trunc, i32>(s, 2),
+            Err(ParseDecimalError::InvalidSyntax),
+            "{s:?}"
+        );
+        assert_eq!(parse_scaled_exact::<u64>(s, 0), Err(ParseDecimalError::InvalidSyntax), "{s:?}");
+    }
+    assert_eq!(parse_scaled_exact::<i32>("1.", 1), Ok(10));
+    assert_eq!(parse_scaled_exact::<i32>(".5", 1), Ok(5));
+    assert_eq!(parse_scaled_exact::<i32>("-.5", 1), Ok(-5));
+    assert_eq!(parse_scaled_exact::<i32>("+007.50", 1), Ok(75));
+    assert_eq!(parse_scaled_exact::<u32>("-0.000", 1), Ok(0));
+    // Syntax errors are reported before overflow.
+    assert_eq!(parse_scaled_exact::<u8>("999x", 0), Err(ParseDecimalError::InvalidSyntax));
+}
+
+#[test]
+fn test_parse_extremes() {
+    assert_eq!(parse_scaled_exact::<i64>("-9223372036854775808", 0), Ok(i64::MIN));
+    assert_eq!(parse_scaled_exact::<i64>("-922337203685477580.8", 1), Ok(i64::MIN));
+    assert_eq!(
+        parse_scaled_exact::<i64>("9223372036854775808", 0),
+        Err(ParseDecimalError::Overflow)
+    );
+    assert_eq!(
+        parse_scaled_by::<Floor, i64>("-9223372036854775808.1", 0),
+        Err(ParseDecimalError::Overflow)
+    );
+    assert_eq!(parse_scaled_by::<Ceil, i64>("-9223372036854775808.1", 0), Ok(i64::MIN));
+    assert_eq!(
+        parse_scaled_by::<Ceil, u128>("340282366920938463463374607431768211454.5", 0),
+        Ok(u128::MAX)
+    );
+    assert_eq!(parse_scaled_by::<Round, u8>("-0.5", 0), Err(ParseDecimalError::Overflow));
+    assert_eq!(parse_scaled_by::<HalfEven, u8>("-0.5", 0), Ok(0));
+    // Many digits beyond the scale, and a large scale.
+    let long = format!("1.{}1", "0".repeat(1000));
+    assert_eq!(parse_scaled_by::<Ceil, i32>(&long, 3), Ok(1001));
+    assert_eq!(parse_scaled_exact::<i32>(&long, 3), Err(ParseDecimalError::Inexact));
+    assert_eq!(parse_scaled_exact::<i32>("0.000", u32::MAX), Ok(0));
+    assert_eq!(parse_scaled_exact::<i32>("1", u32::MAX), Err(ParseDecimalError::Overflow));
+    assert_eq!(ParseDecimalError::Inexact.to_string(), "digits beyond the scale");
+}
@@ -0,0 +1,96 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+use std::ops::RangeInclusive;
+
+use divrem::diophantine::solve;
+
+#[test]
+fn test_small() {
+    let range = -12..=12_i32;
+    for a in -8..=8_i32 {
+        for b in -8..=8 {
+            for c in -20..=20 {
+                let expected: Vec<_> = range
+                    .clone()
+                    .flat_map(|x| range.clone().map(move |y| (x, y)))
+                    .filter(|&(x, y)| a * x + b * y == c)
+                    .collect();
+                let Some(solution) = solve(a, b, c) else {
+                    assert!(a == 0 && b == 0 || expected.is_empty(), "{} x + {} y = {}", a, b, c);
+                    continue;
+                };
+                let (x, y) = (solution.x(), solution.y());
+                assert_eq!(a * x + b * y, c, "{a} x + {b} y = {c}");
+                assert_eq!(a * solution.x_step() + b * solution.y_step(), 0);
+                if b != 0 {
+                    assert!((0..solution.x_step()).contains(&x), "{} x + {} y = {}", a, b, c);
+                }
+                let mut actual: Vec<_> =
+                    solution.solutions_in(range.clone(), range.clone()).unwrap().collect();
+                actual.sort_unstable();
+                assert_eq!(actual, expected, "{a} x + {b} y = {c}");
+                let count = solution.count_in(range.clone(), range.clone()).unwrap();
+                assert_eq!(usize::try_from(count).unwrap(), expected.len());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ranges() {
+    // 3 x - 5 y = 1: x = 2 + 5 t, y = 1 + 3 t.
+    let solution = solve(3, -5, 1).unwrap();
+    assert_eq!((solution.x(), solution.y(), solution.x_step(), solution.y_step()), (2, 1, 5, 3));
+    assert_eq!(solution.parameters_in(-100..=100, 0..=10), 0..=3);
+    assert_eq!(solution.parameters_in(-100..=100, -10..=-2), -3..=-1);
+    assert!(solution.parameters_in(3..=6, 0..=10).is_empty());
+    assert!(solution.parameters_in(0..=10, RangeInclusive::new(5, 0)).is_empty());
+    assert_eq!(solution.count_in(3..=6, 0..=10), Some(0));
+    assert_eq!(solution.solutions_in(3..=6, 0..=10).unwrap().next(), None);
+
+    // `y` is free when `b` is zero.
+    let solution = solve(-4, 0, 12).unwrap();
+    assert_eq!((solution.x(), solution.y(), solution.x_step(), solution.y_step()), (-3, 0, 0, 1));
+    assert_eq!(solution.parameters_in(-3..=-3, -2..=5), -2..=5);
+    assert!(solution.parameters_in(0..=5, -2..=5).is_empty());
+    assert_eq!(solve(-4, 0, 10), None);
+    assert_eq!(solve(0, 0, 0), None);
+}
+
+#[test]
+fn test_extremes() {
+    let solution = solve(i64::MAX, i64::MAX - 1, 1).unwrap();
+    assert_eq!((solution.x(), solution.y()), (1, -1));
+    assert_eq!((solution.x_step(), solution.y_step()), (i64::MAX - 1, -i64::MAX));
+    assert_eq!(solution.count_in(i64::MIN..=i64::MAX, i64::MIN..=i64::MAX), Some(3));
+    assert_eq!(solution.count_in(0..=i64::MAX, -10..=10), Some(1));
+    assert_eq!(solution.checked_at(1), Some((i64::MAX, i64::MIN)));
+    assert_eq!(solution.checked_at(2), None);
+
+    let solution = solve(1_i128, 1, 0).unwrap();
+    assert_eq!(solution.count_in(1..=i128::MAX, i128::MIN + 1..=0), Some(i128::MAX));
+    assert_eq!(solution.count_in(-1..=i128::MAX, i128::MIN..=1), None);
+    let mut solutions = solution.solutions_in(i128::MAX - 1..=i128::MAX, i128::MIN..=0).unwrap();
+    assert_eq!(solutions.next(), Some((i128::MAX - 1, 1 - i128::MAX)));
+    assert_eq!(solutions.next(), Some((i128::MAX, -i128::MAX)));
+    assert_eq!(solutions.next(), None);
+
+    assert_eq!(solve(i32::MIN, 1, 0).map(|s| s.y_step()), None);
+    assert_eq!(solve(2, i32::MIN, 4).map(|s| (s.x(), s.y())), None);
+    assert_eq!(solve(-1, 0, i32::MIN), None);
+}
+
+#[test]
+fn test_large_steps() {
+    // x = t and y = 2^62 * t.
+    let solution = solve(1_i64 << 62, -1, 0).unwrap();
+    assert_eq!((solution.x_step(), solution.y_step()), (1, 1 << 62));
+    assert!(solution.parameters_in(i64::MIN..=i64::MAX, i64::MAX..=i64::MAX).is_empty());
+    assert_eq!(solution.parameters_in(i64::MIN..=i64::MAX, i64::MIN..=i64::MAX), -2..=1);
+    assert_eq!(solution.count_in(i64::MIN..=i64::MAX, 1..=i64::MAX), Some(1));
+}
@@ -0,0 +1,109 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+
+use divrem::prelude::*;
+use divrem::DivError;
+
+/// Reference sums with `i128` arithmetic, or `None` if they overflow, which
+/// only happens with 128-bit arguments.
+fn sums(n: i128, m: i128, a: i128, b: i128) -> Option<[i128; 3]> {
+    let terms = (0..n).map(|i| a.checked_mul(i)?.checked_add(b)).collect::<Option<Vec<_>>>()?;
+    let sum = |div: fn(i128, i128) -> Result<i128, DivError>| {
+        terms.iter().try_fold(0_i128, |sum, &x| sum.checked_add(div(x, m).ok()?))
+    };
+    Some([sum(i128::try_div_floor)?, sum(i128::try_div_ceil)?, sum(i128::try_div_euclid)?])
+}
+
+macro_rules! test_small {
+    ($test_name:ident, $t:ident, $values:expr) => {
+        #[test]
+        fn $test_name() {
+            let values = $values;
+            for n in 0..=12 {
+                for &m in values.iter().filter(|&&m| m != 0) {
+                    for &a in &values {
+                        for &b in &values {
+                            let wide = |x: $t| i128::try_from(x).unwrap();
+                            let Some([floor, ceil, euclid]) =
+                                sums(wide(n), wide(m), wide(a), wide(b))
+                            else {
+                                continue;
+                            };
+                            let narrow = |x: i128| $t::try_from(x).ok();
+                            let args = format!("({n}, {m}, {a}, {b})");
+                            assert_eq!($t::floor_sum(n, m, a, b), narrow(floor), "floor_sum{args}");
+                            assert_eq!($t::ceil_sum(n, m, a, b), narrow(ceil), "ceil_sum{args}");
+                            assert_eq!(
+                                $t::euclid_sum(n, m, a, b),
+                                narrow(euclid),
+                                "euclid_sum{args}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_small!(
+    test_i8,
+    i8,
+    [i8::MIN, i8::MIN + 1, -100, -17, -7, -3, -2, -1, 0, 1, 2, 3, 5, 7, 17, 100, i8::MAX]
+);
+test_small!(test_u8, u8, [0, 1, 2, 3, 5, 7, 17, 100, 200, u8::MAX]);
+test_small!(test_i64, i64, [i64::MIN, -(1 << 40) - 1, -7, -1, 0, 1, 3, 1 << 40, i64::MAX]);
+test_small!(test_u128, u128, [0, 1, 3, 1 << 64, 1 << 100, 1 << 123, (1 << 126) - 1]);
+test_small!(test_i128, i128, [-(1 << 120), -(1 << 64), -7, -1, 0, 1, 3, 1 << 64, 1 << 120]);
+
+#[test]
+fn test_large_n() {
+    // ⌊i / 2^32⌋ for i in 0..2^64: 2^32 copies of each value below 2^32.
+    let expected = (1_u128 << 32) * ((1 << 32) * ((1 << 32) - 1) / 2);
+    assert_eq!(u128::floor_sum(1 << 64, 1 << 32, 1, 0), Some(expected));
+    assert_eq!(i128::floor_sum(1 << 64, 1 << 32, 1, 0), i128::try_from(expected).ok());
+    assert_eq!(i128::floor_sum(1 << 64, -(1 << 32), -1, 0), i128::try_from(expected).ok());
+    assert_eq!(i128::ceil_sum(1 << 64, 1 << 32, -1, 0), i128::try_from(expected).ok().map(|x| -x));
+    // ⌊(MAX * i + MAX) / MAX⌋ = i + 1.
+    assert_eq!(
+        u64::floor_sum(1 << 20, u64::MAX, u64::MAX, u64::MAX),
+        Some((1 << 19) * ((1 << 20) + 1))
+    );
+    assert_eq!(u64::floor_sum(1 << 33, 1, 1 << 31, 0), None);
+    // ⌊(2^20 * i) / 2^20⌋ = i.
+    assert_eq!(i64::floor_sum(1 << 30, 1 << 20, 1 << 20, 0), Some((1 << 29) * ((1 << 30) - 1)));
+    assert_eq!(i64::floor_sum(1 << 33, 1 << 20, 1 << 20, 0), None);
+    // Every `i` that is not a multiple of `2^60` rounds up once more.
+    let floor = (1_u128 << 60) * (1024 * 1023 / 2);
+    assert_eq!(u128::floor_sum(1 << 70, 1 << 60, 1, 0), Some(floor));
+    assert_eq!(u128::ceil_sum(1 << 70, 1 << 60, 1, 0), Some(floor + (1 << 70) - 1024));
+    assert_eq!(u128::ceil_sum(1 << 70, 1 << 60, 1, 1 << 60), Some(floor + (2 << 70) - 1024));
+}
+
+#[test]
+fn test_u128_extremes() {
+    let max = u128::MAX;
+    assert_eq!(u128::floor_sum(2, max, max, max), Some(1 + 2));
+    assert_eq!(u128::ceil_sum(2, max, max - 1, 1), Some(1 + 1));
+    assert_eq!(u128::ceil_sum(3, max, 1, max - 1), Some(1 + 1 + 2));
+    // The last term, `2^128 / MAX`, does not fit in the type but the sum does.
+    assert_eq!(u128::euclid_sum(3, max, 1, max - 1), Some(1 + 1));
+    assert_eq!(u128::floor_sum(max, max, 1, 0), Some(0));
+    assert_eq!(u128::floor_sum(max, 2, 2, 0), None);
+    assert_eq!(i128::floor_sum(i128::MAX, i128::MAX, 1, 0), Some(0));
+    assert_eq!(i128::floor_sum(i128::MAX, i128::MIN, 0, 1), Some(-i128::MAX));
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(i32::floor_sum(-1, 3, 1, 1), None);
+    assert_eq!(i32::ceil_sum(5, 0, 1, 1), None);
+    assert_eq!(u128::floor_sum(5, 0, 1, 1), None);
+    assert_eq!(u128::ceil_sum(5, 0, 1, 1), None);
+    assert_eq!(i32::floor_sum(0, 3, i32::MIN, i32::MIN), Some(0));
+}
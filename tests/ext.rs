@@ -0,0 +1,63 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::num::Wrapping;
+
+use divrem::prelude::*;
+
+macro_rules! test_std {
+    ($test_name:ident, $t:ident) => {
+        #[test]
+        fn $test_name() {
+            for x in $t::MIN..=$t::MAX {
+                for y in ($t::MIN..=$t::MAX).filter(|&y| y != 0) {
+                    if x.checked_div(y).is_none() {
+                        continue;
+                    }
+                    assert_eq!(x.euclid_div(y), x.div_euclid(y));
+                    assert_eq!(x.euclid_rem(y), x.rem_euclid(y));
+                    assert_eq!(x.euclid_div_rem(y), (x.div_euclid(y), x.rem_euclid(y)));
+                }
+            }
+        }
+    };
+}
+
+test_std!(test_std_i8, i8);
+test_std!(test_std_u8, u8);
+
+#[test]
+fn test_std_div_ceil() {
+    for x in u8::MIN..=u8::MAX {
+        for y in 1..=u8::MAX {
+            assert_eq!(x.ceil_div(y), x.div_ceil(y));
+        }
+    }
+}
+
+#[test]
+fn test_overflow_wraps() {
+    let (min, minus_one) = (Wrapping(i8::MIN), Wrapping(-1));
+    assert_eq!(min.euclid_div(minus_one), min);
+    assert_eq!(min.euclid_rem(minus_one), Wrapping(0));
+    assert_eq!(min.euclid_div_rem(minus_one), (min, Wrapping(0)));
+    assert_eq!(min.ceil_div(minus_one), min);
+}
+
+#[test]
+fn test_prefixed_names() {
+    assert_eq!((-7_i32).trunc_div_rem(2), (-3, -1));
+    assert_eq!((-7_i32).floor_div_rem(2), (-4, 1));
+    assert_eq!((-7_i32).ceil_div_rem(-2), (4, 1));
+    assert_eq!((-7_i32).euclid_div_rem(-2), (4, 1));
+    assert_eq!((&7_u32).ceil_div(&2), 4);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_div_euclid_by_zero() {
+    let _ = 1_i32.euclid_div(0);
+}
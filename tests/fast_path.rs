@@ -0,0 +1,96 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::num::Wrapping;
+
+use divrem::prelude::*;
+use divrem::DivInt;
+
+/// Operands around the 64-bit boundaries of the 128-bit fast paths.
+fn signed_operands() -> Vec<i128> {
+    let mut operands = vec![i128::MIN, i128::MIN + 1, i128::MAX];
+    for x in [0, 1, 2, 7, 1 << 32, i128::from(i64::MAX), 1 << 63, 1 << 64, i128::from(u64::MAX)] {
+        for x in [x - 1, x, x + 1] {
+            operands.extend([x, -x]);
+        }
+    }
+    operands
+}
+
+fn unsigned_operands() -> Vec<u128> {
+    let mut operands = vec![u128::MAX - 1, u128::MAX];
+    for x in [1, 2, 7, 1 << 32, 1 << 63, u128::from(u64::MAX), 1 << 64] {
+        operands.extend([x - 1, x, x + 1]);
+    }
+    operands
+}
+
+// `Wrapping` types have no fast path and serve as reference, except for
+// `MIN / -1` which wraps.
+#[test]
+fn test_i128() {
+    let operands = signed_operands();
+    for &x in &operands {
+        for &y in operands.iter().filter(|&&y| y != 0 && !(x == i128::MIN && y == -1)) {
+            let (wx, wy) = (Wrapping(x), Wrapping(y));
+            let pair = |(q, r): (Wrapping<i128>, Wrapping<i128>)| (q.0, r.0);
+            assert_eq!(x.checked_div_rem(y), Some((x / y, x % y)), "{x} / {y}");
+            assert_eq!(x.div_rem(y), pair(wx.div_rem(wy)), "{x} / {y}");
+            assert_eq!(x.floor_div(y), x.floor_div_rem(y).0);
+            assert_eq!(x.floor_div_rem(y), pair(wx.div_rem_floor(wy)), "{x} / {y}");
+            assert_eq!(x.floor_rem(y), wx.rem_floor(wy).0, "{x} / {y}");
+            assert_eq!(x.ceil_div(y), DivCeil::div_ceil(wx, wy).0, "{x} / {y}");
+            assert_eq!(x.ceil_div_rem(y), pair(wx.div_rem_ceil(wy)), "{x} / {y}");
+            assert_eq!(x.ceil_rem(y), wx.rem_ceil(wy).0, "{x} / {y}");
+            assert_eq!(x.euclid_div(y), x.div_euclid(y), "{x} / {y}");
+            assert_eq!(x.euclid_rem(y), x.rem_euclid(y), "{x} / {y}");
+            assert_eq!(x.euclid_div_rem(y), pair(wx.div_rem_euclid(wy)), "{x} / {y}");
+        }
+    }
+}
+
+#[test]
+fn test_u128() {
+    let operands = unsigned_operands();
+    for &x in &operands {
+        for &y in operands.iter().filter(|&&y| y != 0) {
+            let (wx, wy) = (Wrapping(x), Wrapping(y));
+            let pair = |(q, r): (Wrapping<u128>, Wrapping<u128>)| (q.0, r.0);
+            assert_eq!(x.checked_div_rem(y), Some((x / y, x % y)), "{x} / {y}");
+            assert_eq!(x.div_rem(y), pair(wx.div_rem(wy)), "{x} / {y}");
+            assert_eq!(x.floor_div_rem(y), pair(wx.div_rem_floor(wy)), "{x} / {y}");
+            assert_eq!(x.ceil_div(y), x.div_ceil(y), "{x} / {y}");
+            assert_eq!(DivInt::checked_div_ceil(x, y), Some(x.div_ceil(y)), "{x} / {y}");
+            assert_eq!(x.euclid_div_rem(y), pair(wx.div_rem_euclid(wy)), "{x} / {y}");
+        }
+    }
+}
+
+#[test]
+fn test_narrow_overflow() {
+    // Overflows in 64 bits but not in 128 bits.
+    let (min, minus_one) = (i128::from(i64::MIN), -1);
+    assert_eq!(min.checked_div_rem(minus_one), Some((1 << 63, 0)));
+    assert_eq!(min.floor_div_rem(minus_one), (1 << 63, 0));
+    assert_eq!(min.euclid_div_rem(minus_one), (1 << 63, 0));
+    assert_eq!(DivInt::checked_div_euclid(min, minus_one), Some(1 << 63));
+    assert_eq!(DivInt::checked_div_ceil(min, minus_one), Some(1 << 63));
+    assert_eq!(i128::MIN.checked_div_rem(-1), None);
+    assert_eq!(1_i128.checked_div_rem(0), None);
+    assert_eq!(DivInt::checked_div_ceil(1_u128, 0), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_zero() {
+    let _ = 1_u128.div_rem_floor(0);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn test_overflow() {
+    let _ = i128::MIN.div_rem_euclid(-1);
+}
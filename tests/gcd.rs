@@ -0,0 +1,92 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+
+use divrem::prelude::*;
+
+/// Reference greatest common divisor.
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+macro_rules! test_exhaustive {
+    ($test_name:ident, $t:ident) => {
+        #[test]
+        fn $test_name() {
+            for a in $t::MIN..=$t::MAX {
+                for b in $t::MIN..=$t::MAX {
+                    let (wa, wb) = (i32::from(a), i32::from(b));
+                    let g = gcd(wa, wb);
+                    assert_eq!(a.checked_gcd(b), $t::try_from(g).ok(), "gcd({a}, {b})");
+                    let lcm = if g == 0 { 0 } else { (wa / g * wb).abs() };
+                    assert_eq!(a.checked_lcm(b), $t::try_from(lcm).ok(), "lcm({a}, {b})");
+                    let inverse = (0..wb).find(|&x| (wa * x).rem_euclid(wb) == 1 % wb);
+                    assert_eq!(a.mod_inverse(b).map(i32::from), inverse, "1 / {a} mod {b}");
+                }
+            }
+        }
+    };
+}
+
+test_exhaustive!(test_i8, i8);
+test_exhaustive!(test_u8, u8);
+
+#[test]
+fn test_extended_i8() {
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            let (wa, wb) = (i32::from(a), i32::from(b));
+            let g = gcd(wa, wb);
+            let Some((eg, x, y)) = a.checked_extended_gcd(b) else {
+                assert!(i8::try_from(g).is_err(), "extended_gcd({}, {})", a, b);
+                continue;
+            };
+            let (x, y) = (i32::from(x), i32::from(y));
+            assert_eq!((i32::from(eg), wa * x + wb * y), (g, g), "extended_gcd({a}, {b})");
+            if wa != 0 && wb != 0 && wa % wb != 0 && wb % wa != 0 {
+                assert!(x.abs() * g <= wb.abs() && y.abs() * g <= wa.abs(), "({}, {})", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_wide() {
+    assert_eq!(i128::MIN.checked_gcd(0), None);
+    assert_eq!(i128::MIN.checked_gcd(i128::MIN), None);
+    assert_eq!(i128::MIN.gcd(-1), 1);
+    assert_eq!(i128::MIN.gcd(6), 2);
+    assert_eq!(i128::MIN.extended_gcd(-1), (1, 0, -1));
+    assert_eq!(i128::MIN.extended_gcd(i128::MAX), (1, -1, -1));
+    assert_eq!(u128::MAX.gcd(u128::MAX - 1), 1);
+    assert_eq!(u128::MAX.lcm(1), u128::MAX);
+    assert_eq!(u128::MAX.checked_lcm(2), None);
+    assert_eq!((-4_i64).lcm(6), 12);
+    assert_eq!((-3_i64).mod_inverse(7), Some(2));
+    assert_eq!(3_i64.mod_inverse(-7), None);
+    assert_eq!(5_u32.mod_inverse(1), Some(0));
+    let p = u128::MAX - 158; // The largest 128-bit prime.
+    assert_eq!(2_u128.mod_inverse(p), Some(p / 2 + 1));
+    assert_eq!(usize::MAX.mod_inverse(usize::MAX - 1), Some(1));
+    assert_eq!(isize::MIN.mod_inverse(isize::MAX), Some(isize::MAX - 1));
+}
+
+#[test]
+#[should_panic(expected = "attempt to compute the gcd with overflow")]
+fn test_gcd_overflow() {
+    let _ = i32::MIN.gcd(0);
+}
+
+#[test]
+#[should_panic(expected = "attempt to compute the lcm with overflow")]
+fn test_lcm_overflow() {
+    let _ = i32::MAX.lcm(2);
+}
@@ -0,0 +1,87 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use divrem::prelude::*;
+use divrem::DivInt;
+
+/// A third-party integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Int(i16);
+
+macro_rules! impl_op {
+    ($($trait:ident::$method:ident),*) => {$(
+        impl $trait for Int {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                Self(self.0.$method(other.0))
+            }
+        }
+    )*};
+}
+
+impl_op!(Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+
+impl Neg for Int {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl DivInt for Int {
+    const IS_SIGNED: bool = true;
+    const MAX: Self = Self(i16::MAX);
+    const MIN: Self = Self(i16::MIN);
+    const ONE: Self = Self(1);
+    const ZERO: Self = Self(0);
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        self.0.checked_mul(other.0).map(Self)
+    }
+
+    fn checked_div(self, other: Self) -> Option<Self> {
+        self.0.checked_div(other.0).map(Self)
+    }
+
+    fn checked_rem(self, other: Self) -> Option<Self> {
+        self.0.checked_rem(other.0).map(Self)
+    }
+}
+
+fn pair((q, r): (i16, i16)) -> (Int, Int) {
+    (Int(q), Int(r))
+}
+
+#[test]
+fn test_third_party_int() {
+    for x in -32_i16..32 {
+        for y in (-8_i16..8).filter(|&y| y != 0) {
+            let (ix, iy) = (Int(x), Int(y));
+            assert_eq!(ix.div_rem(iy), pair(x.div_rem(y)));
+            assert_eq!(ix.div_floor(iy), Int(DivFloor::div_floor(x, y)));
+            assert_eq!(ix.rem_floor(iy), Int(x.rem_floor(y)));
+            assert_eq!(ix.div_rem_floor(iy), pair(x.div_rem_floor(y)));
+            assert_eq!(ix.div_ceil(iy), Int(DivCeil::div_ceil(x, y)));
+            assert_eq!(ix.rem_ceil(iy), Int(x.rem_ceil(y)));
+            assert_eq!(ix.div_rem_ceil(iy), pair(x.div_rem_ceil(y)));
+            assert_eq!(ix.div_euclid(iy), Int(DivEuclid::div_euclid(x, y)));
+            assert_eq!(ix.rem_euclid(iy), Int(RemEuclid::rem_euclid(x, y)));
+            assert_eq!(ix.div_rem_euclid(iy), pair(x.div_rem_euclid(y)));
+        }
+    }
+}
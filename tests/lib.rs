@@ -3,7 +3,7 @@
 #![warn(clippy::cargo)]
 #![warn(clippy::nursery)]
 #![allow(clippy::missing_const_for_fn)]
-#![feature(concat_idents)]
+#![feature(macro_metavar_expr_concat)]
 
 #[rustfmt::skip]
 const XY: [(i32, i32); 8] =
@@ -42,12 +42,16 @@ macro_rules! div_rem_functions {
         }
 
         fn div_rem_trunc(x: $t, y: $t) -> ($t, $t) {
+            #[allow(clippy::cast_precision_loss)] // Test operands are small.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // q is an integer <= |x|.
             let q = ((x as f32) / (y as f32)).trunc() as $t;
             let r = x - q * y;
             (q, r)
         }
 
         fn div_rem_floor(x: $t, y: $t) -> ($t, $t) {
+            #[allow(clippy::cast_precision_loss)] // Test operands are small.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // q is an integer <= |x|.
             let q = ((x as f32) / (y as f32)).floor() as $t;
             let r = x - q * y;
             (q, r)
@@ -125,9 +129,9 @@ mod signed {
                 use super::{$table, XY};
 
                 for (&(x, y), &tqr) in XY.iter().zip(&$table) {
-                    let q = concat_idents!(div_, $variant)(x, y);
-                    let r = concat_idents!(rem_, $variant)(x, y);
-                    let qr = concat_idents!(div_rem_, $variant)(x, y);
+                    let q = ${concat(div_, $variant)}(x, y);
+                    let r = ${concat(rem_, $variant)}(x, y);
+                    let qr = ${concat(div_rem_, $variant)}(x, y);
                     assert_eq!(tqr, (q, r));
                     assert_eq!(tqr, qr);
                 }
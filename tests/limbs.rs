@@ -0,0 +1,233 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+#![allow(clippy::cast_possible_truncation)] // Splitting into limbs.
+#![allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)] // Two's complement limbs.
+
+use divrem::limbs;
+use divrem::prelude::*;
+
+/// Pseudo-random values, with small and extreme values mixed in.
+fn values() -> impl Iterator<Item = u128> {
+    let mut x = 0x2545_f491_4f6c_dd1d_u128;
+    (0..300_u128).map(move |i| {
+        x = x.wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645).wrapping_add(1);
+        match i % 4 {
+            0 => x,
+            1 => x >> (x % 128),
+            2 => i / 4,
+            _ => x.wrapping_neg() >> (x % 64),
+        }
+    })
+}
+
+fn to_u32(n: u128) -> [u32; 4] {
+    [n as u32, (n >> 32) as u32, (n >> 64) as u32, (n >> 96) as u32]
+}
+
+fn from_u32(n: [u32; 4]) -> u128 {
+    n.iter().rev().fold(0, |acc, &limb| acc << 32 | u128::from(limb))
+}
+
+fn to_u64(n: u128) -> [u64; 2] {
+    [n as u64, (n >> 64) as u64]
+}
+
+fn from_u64(n: [u64; 2]) -> u128 {
+    u128::from(n[1]) << 64 | u128::from(n[0])
+}
+
+#[test]
+fn test_unsigned() {
+    for n in values() {
+        for d in values().map(|d| d as u32).filter(|&d| d != 0) {
+            let mut limbs = to_u32(n);
+            let r = limbs::div_rem(&mut limbs, d);
+            assert_eq!((from_u32(limbs), u128::from(r)), n.div_rem(u128::from(d)));
+        }
+        for d in values().map(|d| d as u64).filter(|&d| d != 0) {
+            let mut limbs = to_u64(n);
+            let r = limbs::div_rem(&mut limbs, d);
+            assert_eq!((from_u64(limbs), u128::from(r)), n.div_rem(u128::from(d)));
+        }
+    }
+}
+
+macro_rules! test_signed {
+    ($test_name:ident, $function:ident, $method:ident) => {
+        #[test]
+        fn $test_name() {
+            for n in values().map(|n| n as i128) {
+                for d in values().map(|d| d as i32).filter(|&d| d != 0) {
+                    let mut limbs = to_u32(n as u128);
+                    let r = limbs::$function(&mut limbs, d);
+                    let expected = n.$method(i128::from(d));
+                    assert_eq!((from_u32(limbs) as i128, i128::from(r)), expected);
+                }
+                for d in values().map(|d| d as i64).filter(|&d| d != 0) {
+                    let mut limbs = to_u64(n as u128);
+                    let r = limbs::$function(&mut limbs, d);
+                    let expected = n.$method(i128::from(d));
+                    assert_eq!((from_u64(limbs) as i128, i128::from(r)), expected);
+                }
+            }
+        }
+    };
+}
+
+test_signed!(test_signed_trunc, div_rem_signed, trunc_div_rem);
+test_signed!(test_signed_floor, div_rem_signed_floor, floor_div_rem);
+test_signed!(test_signed_ceil, div_rem_signed_ceil, ceil_div_rem);
+test_signed!(test_signed_euclid, div_rem_signed_euclid, euclid_div_rem);
+
+#[test]
+fn test_edge_cases() {
+    let mut empty: [u64; 0] = [];
+    assert_eq!(limbs::div_rem_signed_floor(&mut empty, -3), 0);
+    let mut min = to_u64(i128::MIN as u128);
+    assert_eq!(limbs::div_rem_signed_ceil(&mut min, i64::MIN), 0);
+    assert_eq!(from_u64(min), 1 << 64);
+    let mut min = to_u64(i128::MIN as u128);
+    assert_eq!(limbs::div_rem_signed_floor(&mut min, 1), 0);
+    assert_eq!(from_u64(min) as i128, i128::MIN);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn test_overflow() {
+    let mut min = to_u32(i128::MIN as u128);
+    limbs::div_rem_signed(&mut min, -1);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_division_by_zero() {
+    limbs::div_rem(&mut [1_u32, 2], 0);
+}
+
+#[test]
+fn test_long_unsigned() {
+    for n in values() {
+        for d in values().filter(|&d| d != 0) {
+            let expected = n.div_rem(d);
+            let (mut limbs, mut q) = (to_u32(n), [0; 4]);
+            limbs::div_rem_long(&mut limbs, &to_u32(d), &mut q);
+            assert_eq!((from_u32(q), from_u32(limbs)), expected);
+            let (mut limbs, mut q) = (to_u64(n), [0; 2]);
+            limbs::div_rem_long(&mut limbs, &to_u64(d), &mut q);
+            assert_eq!((from_u64(q), from_u64(limbs)), expected);
+        }
+    }
+}
+
+macro_rules! test_long_signed {
+    ($test_name:ident, $function:ident, $method:ident) => {
+        #[test]
+        fn $test_name() {
+            for n in values().map(|n| n as i128) {
+                for d in values().map(|d| d as i128).filter(|&d| d != 0) {
+                    if n == i128::MIN && d == -1 {
+                        continue;
+                    }
+                    let expected = n.$method(d);
+                    let (mut limbs, mut q) = (to_u32(n as u128), [0; 4]);
+                    limbs::$function(&mut limbs, &to_u32(d as u128), &mut q);
+                    assert_eq!((from_u32(q) as i128, from_u32(limbs) as i128), expected);
+                    let (mut limbs, mut q) = (to_u64(n as u128), [0; 2]);
+                    limbs::$function(&mut limbs, &to_u64(d as u128), &mut q);
+                    assert_eq!((from_u64(q) as i128, from_u64(limbs) as i128), expected);
+                }
+            }
+        }
+    };
+}
+
+test_long_signed!(test_long_signed_trunc, div_rem_long_signed, trunc_div_rem);
+test_long_signed!(test_long_signed_floor, div_rem_long_signed_floor, floor_div_rem);
+test_long_signed!(test_long_signed_ceil, div_rem_long_signed_ceil, ceil_div_rem);
+test_long_signed!(test_long_signed_euclid, div_rem_long_signed_euclid, euclid_div_rem);
+
+/// Schoolbook multiplication of `a` by `b` plus `c`.
+fn mul_add(a: &[u64], b: &[u64], c: &[u64]) -> Vec<u64> {
+    let mut product = vec![0; a.len() + b.len()];
+    product[..c.len()].copy_from_slice(c);
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (offset, &y) in (i..).zip(b) {
+            let sum = u128::from(x) * u128::from(y) + u128::from(product[offset]) + carry;
+            product[offset] = sum as u64;
+            carry = sum >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+    product
+}
+
+#[test]
+fn test_long_wide() {
+    let words: Vec<u64> = values().map(|x| x as u64 | 1).collect();
+    for (a_len, b_len) in [(1, 2), (3, 3), (5, 2), (4, 7), (8, 5)] {
+        for chunk in words.chunks_exact(a_len + 2 * b_len) {
+            let (quotient, rest) = chunk.split_at(a_len);
+            let (divisor, remainder) = rest.split_at(b_len);
+            // Keep the remainder below the divisor.
+            let mut remainder = remainder.to_vec();
+            remainder[b_len - 1] = remainder[b_len - 1].min(divisor[b_len - 1] - 1);
+            let mut n = mul_add(quotient, divisor, &remainder);
+            let mut q = vec![0; n.len()];
+            limbs::div_rem_long(&mut n, divisor, &mut q);
+            assert_eq!(&q[..a_len], quotient);
+            assert!(q[a_len..].iter().all(|&limb| limb == 0));
+            assert_eq!(&n[..b_len], &remainder[..]);
+            assert!(n[b_len..].iter().all(|&limb| limb == 0));
+        }
+    }
+}
+
+#[test]
+fn test_long_add_back() {
+    // Knuth's estimate is one too large for this dividend.
+    let mut n = [0_u32, 0, 0x8000_0000, 0x7fff_ffff];
+    let mut q = [0; 4];
+    limbs::div_rem_long(&mut n, &[1, 0, 0x8000_0000], &mut q);
+    assert_eq!(q, [0xffff_fffe, 0, 0, 0]);
+    assert_eq!(n, [2, 0xffff_ffff, 0x7fff_ffff, 0]);
+}
+
+#[test]
+fn test_long_edge_cases() {
+    let mut n = [7_u64, 0, 0];
+    let mut q = [1; 3];
+    limbs::div_rem_long(&mut n, &[0, 1], &mut q);
+    assert_eq!((n, q), ([7, 0, 0], [0; 3]));
+    let mut min = to_u64(i128::MIN as u128);
+    let mut q = [0; 3];
+    limbs::div_rem_long_signed(&mut min, &[u64::MAX], &mut q);
+    assert_eq!((min, q), ([0, 0], [0, 1 << 63, 0]));
+    let mut one = [1_u64, 0];
+    let mut q = [0; 2];
+    limbs::div_rem_long_signed_floor(&mut one, &to_u64(i128::MIN as u128), &mut q);
+    assert_eq!(from_u64(q) as i128, -1);
+    assert_eq!(from_u64(one) as i128, i128::MIN + 1);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn test_long_overflow() {
+    let mut min = to_u32(i128::MIN as u128);
+    limbs::div_rem_long_signed_euclid(&mut min, &[u32::MAX], &mut [0; 4]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_long_division_by_zero() {
+    limbs::div_rem_long(&mut [1_u32, 2], &[0, 0], &mut [0; 2]);
+}
+
+#[test]
+#[should_panic(expected = "remainder buffer too short")]
+fn test_long_short_remainder() {
+    limbs::div_rem_long_signed_floor(&mut [1_u32], &[1, u32::MAX], &mut [0; 1]);
+}
@@ -0,0 +1,119 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryInto;
+
+use divrem::{DynMod, Mod};
+
+/// Reference inverse of `a` modulo `m`, by exhaustive search.
+fn inverse(a: i64, m: i64) -> Option<i64> {
+    (0..m).find(|&x| (a * x).rem_euclid(m) == 1 % m)
+}
+
+#[test]
+fn test_small_moduli() {
+    for m in 1..=30_i64 {
+        for a in -40..40 {
+            let x = DynMod::new(a, m);
+            assert_eq!(x.value(), a.rem_euclid(m));
+            assert_eq!((-x).value(), (-a).rem_euclid(m));
+            assert_eq!(x.pow(5).value(), a.pow(5).rem_euclid(m));
+            assert_eq!(x.inverse().map(DynMod::value), inverse(a, m), "1 / {a} (mod {m})");
+            for b in -10..10 {
+                let y = DynMod::new(b, m);
+                assert_eq!((x + y).value(), (a + b).rem_euclid(m));
+                assert_eq!((x - y).value(), (a - b).rem_euclid(m));
+                assert_eq!((x * y).value(), (a * b).rem_euclid(m));
+                let quotient = inverse(b, m).map(|inverse| (a * inverse).rem_euclid(m));
+                assert_eq!(x.checked_div(y).map(DynMod::value), quotient, "{a} / {b} (mod {m})");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_const_modulus() {
+    type M13 = Mod<13>;
+    for a in -30..30 {
+        let x = M13::new(a);
+        let y = DynMod::new(a, 13);
+        assert_eq!(u128::from(x.value()), y.value().unsigned_abs());
+        assert_eq!(
+            u128::from((x * x + M13::new(3)).value()),
+            (y * y + DynMod::new(3, 13)).value().unsigned_abs()
+        );
+        assert_eq!(
+            x.inverse().map(Mod::value),
+            inverse(a.try_into().unwrap(), 13).map(|x| x.try_into().unwrap())
+        );
+    }
+    assert_eq!(M13::MODULUS, 13);
+}
+
+#[test]
+fn test_large_moduli() {
+    // Mersenne primes: every nonzero value is invertible and a^(p - 1) = 1.
+    const P61: u64 = (1 << 61) - 1;
+    type M61 = Mod<P61>;
+    let p127 = (1_u128 << 127) - 1;
+    let mut a = 0x2545_f491_4f6c_dd1d_u128;
+    for _ in 0..100 {
+        a = a.wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645).wrapping_add(1);
+        let x = M61::new(a.try_into().unwrap_or(i128::MAX));
+        assert_eq!(x.pow(P61 - 1), M61::new(1));
+        assert_eq!(x * x.inverse().unwrap(), M61::new(1));
+        assert_eq!(x / x, M61::new(1));
+
+        let y = DynMod::new(a, p127);
+        let one = DynMod::new(1, p127);
+        assert_eq!(y * y.inverse().unwrap(), one);
+        assert_eq!(
+            y.pow(u64::MAX) * y.pow(u64::MAX),
+            y.pow(u64::MAX - 63) * y.pow(63) * y.pow(u64::MAX)
+        );
+        assert_eq!((y - one) + one, y);
+    }
+
+    let max = DynMod::new(u128::MAX - 1, u128::MAX);
+    assert_eq!((max + max).value(), u128::MAX - 2);
+    assert_eq!((max * max).value(), 1);
+    assert_eq!(Mod::<{ u64::MAX }>::new(-1).value(), u64::MAX - 1);
+    assert_eq!((Mod::<{ u64::MAX }>::new(-1) * Mod::new(-1)).value(), 1);
+    assert_eq!(DynMod::new(i128::MIN, i128::MAX).value(), i128::MAX - 1);
+}
+
+#[test]
+fn test_fmt() {
+    assert_eq!(Mod::<7>::new(-1).to_string(), "6");
+    assert_eq!(format!("{:03}", Mod::<7>::new(-1)), "006");
+    assert_eq!(format!("{:?}", Mod::<7>::new(-1)), "6 (mod 7)");
+    assert_eq!(format!("{:?}", DynMod::new(-1_i8, 7)), "6 (mod 7)");
+}
+
+#[test]
+fn test_modulus_one() {
+    assert_eq!(Mod::<1>::new(5).pow(0), Mod::new(0));
+    assert_eq!(Mod::<1>::new(5).inverse(), Some(Mod::new(0)));
+    assert_eq!(DynMod::new(5_u8, 1).pow(0).value(), 0);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by a non-invertible residue")]
+fn test_non_invertible() {
+    let _ = Mod::<8>::new(1) / Mod::new(6);
+}
+
+#[test]
+#[should_panic(expected = "attempt to combine residues with different moduli")]
+fn test_different_moduli() {
+    let _ = DynMod::new(1_u32, 7) + DynMod::new(1, 8);
+}
+
+#[test]
+#[should_panic(expected = "attempt to use a non-positive modulus")]
+fn test_non_positive_modulus() {
+    let _ = DynMod::new(1_i32, -7);
+}
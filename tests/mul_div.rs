@@ -0,0 +1,131 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+
+use divrem::prelude::*;
+use divrem::rounding::{Ceil, Euclid, Floor, HalfEven, Round, Trunc};
+use divrem::MulDiv;
+
+mod common;
+
+use common::div_round;
+
+/// Reference results of `a * b / c` in every mode.
+fn reference(a: i128, b: i128, c: i128) -> [i128; 5] {
+    let n = a * b;
+    [n / c, n.floor_div(c), n.ceil_div(c), n.euclid_div(c), div_round(n, c)]
+}
+
+macro_rules! test_small {
+    ($test_name:ident, $t:ident, $step:expr) => {
+        #[test]
+        fn $test_name() {
+            for a in ($t::MIN..=$t::MAX).step_by($step) {
+                for b in ($t::MIN..=$t::MAX).step_by($step) {
+                    for c in ($t::MIN..=$t::MAX).step_by($step) {
+                        let modes = [
+                            a.mul_div_trunc(b, c),
+                            a.mul_div_floor(b, c),
+                            a.mul_div_ceil(b, c),
+                            a.mul_div_euclid(b, c),
+                            a.mul_div_round(b, c),
+                        ];
+                        if c == 0 {
+                            assert_eq!(modes, [None; 5]);
+                            continue;
+                        }
+                        let expected = reference(a.into(), b.into(), c.into());
+                        for (q, e) in modes.iter().zip(&expected) {
+                            assert_eq!(*q, $t::try_from(*e).ok(), "{} * {} / {}", a, b, c);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_small!(test_i8, i8, 3);
+test_small!(test_u8, u8, 3);
+test_small!(test_i16, i16, 997);
+test_small!(test_u16, u16, 997);
+test_small!(test_i32, i32, 50_331_653);
+test_small!(test_u32, u32, 50_331_653);
+
+#[test]
+fn test_mode_parameter() {
+    assert_eq!(7_i64.mul_div_by::<Trunc>(-3, 2), Some(-10));
+    assert_eq!(7_i64.mul_div_by::<Floor>(-3, 2), Some(-11));
+    assert_eq!(7_i64.mul_div_by::<Ceil>(-3, 2), Some(-10));
+    assert_eq!(7_i64.mul_div_by::<Euclid>(-3, -2), Some(11));
+    assert_eq!(7_i64.mul_div_by::<Round>(-3, 2), Some(-11));
+    assert_eq!(7_usize.mul_div_by::<Round>(3, 2), Some(11));
+    assert_eq!((-7_isize).mul_div_by::<Round>(3, 4), Some(-5));
+    assert_eq!(7_i64.mul_div_by::<HalfEven>(3, 2), Some(10));
+    assert_eq!(7_i64.mul_div_by::<HalfEven>(-3, 2), Some(-10));
+    assert_eq!(5_u32.mul_div_by::<HalfEven>(3, 2), Some(8));
+    assert_eq!((-7_isize).mul_div_by::<HalfEven>(3, 4), Some(-5));
+}
+
+#[test]
+fn test_no_intermediate_overflow() {
+    assert_eq!(u64::MAX.mul_div_floor(u64::MAX, u64::MAX), Some(u64::MAX));
+    assert_eq!(i64::MIN.mul_div_floor(i64::MIN, i64::MIN), Some(i64::MIN));
+    assert_eq!(i64::MIN.mul_div_floor(-1, 1), None);
+    assert_eq!(i64::MIN.mul_div_floor(-1, -1), Some(i64::MIN));
+    assert_eq!(u64::MAX.mul_div_ceil(3, 2), None);
+    // 90 kHz timestamps to nanoseconds.
+    assert_eq!(123_456_789_u64.mul_div_round(1_000_000_000, 90_000), Some(1_371_742_100_000));
+}
+
+#[test]
+fn test_128() {
+    assert_eq!(u128::MAX.mul_div_floor(u128::MAX, u128::MAX), Some(u128::MAX));
+    assert_eq!(u128::MAX.mul_div_ceil(u128::MAX - 1, u128::MAX), Some(u128::MAX - 1));
+    assert_eq!(u128::MAX.mul_div_floor(2, 1), None);
+    assert_eq!(u128::MAX.mul_div_ceil(u128::MAX, u128::MAX - 1), None);
+    assert_eq!(u128::MAX.mul_div_floor(u128::MAX, u128::MAX - 1), None);
+    assert_eq!(u128::MAX.mul_div_floor(u128::MAX - 1, u128::MAX), Some(u128::MAX - 1));
+    assert_eq!(i128::MIN.mul_div_floor(i128::MIN, i128::MIN), Some(i128::MIN));
+    assert_eq!(i128::MIN.mul_div_floor(i128::MAX, i128::MAX), Some(i128::MIN));
+    assert_eq!(i128::MAX.mul_div_floor(i128::MIN, i128::MIN), Some(i128::MAX));
+    assert_eq!(i128::MIN.mul_div_floor(-1, 1), None);
+    assert_eq!(i128::MAX.mul_div_round(3, 6), Some(i128::MAX / 2 + 1));
+    assert_eq!(i128::MAX.mul_div_floor(-3, 6), Some(-(i128::MAX / 2) - 1));
+    let (a, b, c) = (10_u128.pow(30), 10_u128.pow(30) + 1, 7 * 10_u128.pow(24));
+    assert_eq!(a.mul_div_floor(b, c), Some(142_857_142_857_142_857_142_857_142_857_285_714));
+    assert_eq!(a.mul_div_ceil(b, c), Some(142_857_142_857_142_857_142_857_142_857_285_715));
+    assert_eq!(a.mul_div_round(b, c), a.mul_div_floor(b, c));
+    let (a, b, c) = ((1 << 127) - 3, (1 << 127) + 5, (1 << 126) + 1);
+    assert_eq!(a.mul_div_floor(b, c), Some(u128::MAX));
+    assert_eq!(a.mul_div_round(b, c), None);
+}
+
+#[test]
+fn test_128_small_operands() {
+    for a in (-400_i128..400).step_by(7) {
+        for b in (-400_i128..400).step_by(13) {
+            for c in (-400_i128..400).step_by(11).filter(|&c| c != 0) {
+                let modes = [
+                    a.mul_div_trunc(b, c),
+                    a.mul_div_floor(b, c),
+                    a.mul_div_ceil(b, c),
+                    a.mul_div_euclid(b, c),
+                    a.mul_div_round(b, c),
+                ];
+                let expected = reference(a, b, c);
+                assert_eq!(modes, [0, 1, 2, 3, 4].map(|i| Some(expected[i])));
+                let shift = |x: i128| u128::try_from(x.abs()).unwrap() << 60;
+                let (a, b, c) = (shift(a), shift(b), shift(c));
+                if c != 0 && a.checked_mul(b >> 60).is_some() {
+                    let q = (a * (b >> 60)) / (c >> 60);
+                    assert_eq!(a.mul_div_trunc(b, c), Some(q));
+                }
+            }
+        }
+    }
+}
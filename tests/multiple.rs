@@ -0,0 +1,99 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+
+use divrem::prelude::*;
+use divrem::rounding::{Ceil, Euclid, Floor, HalfEven, Round, Trunc};
+
+mod common;
+
+use common::div_round;
+
+/// Reference multiples of `m` near `x`: trunc, floor, ceil, euclid, round,
+/// down and up.
+fn reference(x: i32, m: i32) -> [i32; 7] {
+    let (floor, ceil) = (x.floor_div(m) * m, x.ceil_div(m) * m);
+    let (down, up) = if m > 0 { (floor, ceil) } else { (ceil, floor) };
+    let round = i32::try_from(div_round(x.into(), m.into())).unwrap() * m;
+    [x / m * m, floor, ceil, x.euclid_div(m) * m, round, down, up]
+}
+
+macro_rules! test_exhaustive {
+    ($test_name:ident, $t:ident) => {
+        #[test]
+        fn $test_name() {
+            for x in $t::MIN..=$t::MAX {
+                for m in $t::MIN..=$t::MAX {
+                    let multiples = [
+                        x.checked_round_to_multiple_by::<Trunc>(m),
+                        x.checked_round_to_multiple_by::<Floor>(m),
+                        x.checked_round_to_multiple_by::<Ceil>(m),
+                        x.checked_round_to_multiple_by::<Euclid>(m),
+                        x.checked_round_to_nearest_multiple(m),
+                        x.checked_round_down_to_multiple(m),
+                        x.checked_round_up_to_multiple(m),
+                    ];
+                    if m == 0 {
+                        assert_eq!(multiples, [None; 7]);
+                        assert_eq!(RoundToMultiple::is_multiple_of(x, m), x == 0);
+                        continue;
+                    }
+                    let expected = reference(x.into(), m.into());
+                    for (multiple, &e) in multiples.iter().zip(&expected) {
+                        assert_eq!(*multiple, $t::try_from(e).ok(), "{} to multiple of {}", x, m);
+                    }
+                    let is_multiple = i32::from(x) % i32::from(m) == 0;
+                    assert_eq!(RoundToMultiple::is_multiple_of(x, m), is_multiple);
+                }
+            }
+        }
+    };
+}
+
+test_exhaustive!(test_i8, i8);
+test_exhaustive!(test_u8, u8);
+
+#[test]
+fn test_floor_ceil_remainders() {
+    for x in (i32::MIN..=i32::MAX).step_by(50_331_653) {
+        for m in (i32::MIN..=i32::MAX).step_by(50_331_653).filter(|&m| m != 0) {
+            assert_eq!(x.checked_round_to_multiple_by::<Floor>(m), x.checked_sub(x.rem_floor(m)));
+            assert_eq!(x.checked_round_to_multiple_by::<Ceil>(m), x.checked_sub(x.rem_ceil(m)));
+            assert_eq!(x.checked_round_to_multiple_by::<Euclid>(m), x.checked_sub(x.euclid_rem(m)));
+        }
+    }
+}
+
+#[test]
+fn test_edge_cases() {
+    assert_eq!(i64::MIN.round_down_to_multiple(-1), i64::MIN);
+    assert_eq!(i64::MIN.round_up_to_multiple(i64::MIN), i64::MIN);
+    assert_eq!(i64::MAX.checked_round_to_nearest_multiple(i64::MIN), None);
+    assert_eq!((-i64::MAX).round_to_nearest_multiple(i64::MIN), i64::MIN);
+    assert_eq!((-7_i64).round_to_multiple_by::<Floor>(-4), -4);
+    assert_eq!((-7_i64).round_down_to_multiple(-4), -8);
+    assert_eq!((-6_i64).round_to_nearest_multiple(4), -8);
+    assert_eq!((-6_i64).round_to_multiple_by::<Round>(-4), -8);
+    assert_eq!((-6_i64).round_to_multiple_by::<HalfEven>(4), -8);
+    assert_eq!((-10_i64).round_to_multiple_by::<HalfEven>(4), -8);
+    assert_eq!(10_i64.round_to_multiple_by::<HalfEven>(-4), 8);
+    assert_eq!(i64::MAX.checked_round_up_to_multiple(2), None);
+    assert!(i64::MIN.is_multiple_of(-1));
+    assert_eq!(7_u64.round_up_to_multiple(4), 8);
+}
+
+#[test]
+#[should_panic(expected = "attempt to round to a multiple with overflow")]
+fn test_overflow() {
+    let _ = i32::MIN.round_down_to_multiple(3);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_zero() {
+    let _ = 1_i32.round_to_nearest_multiple(0);
+}
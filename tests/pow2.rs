@@ -0,0 +1,142 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+
+use divrem::prelude::*;
+use divrem::rounding::{Ceil, Euclid, Floor, HalfEven, Round, Trunc};
+
+mod common;
+
+use common::div_round;
+
+/// Reference results of `n / 2^k` in every mode.
+fn reference(n: i128, k: u32) -> [(i128, i128); 5] {
+    let d = 1 << k;
+    let round = div_round(n, d);
+    [
+        n.trunc_div_rem(d),
+        n.floor_div_rem(d),
+        n.ceil_div_rem(d),
+        n.euclid_div_rem(d),
+        (round, n - round * d),
+    ]
+}
+
+macro_rules! test_signed {
+    ($test_name:ident, $t:ident, $step:expr) => {
+        #[test]
+        fn $test_name() {
+            for n in ($t::MIN..=$t::MAX).step_by($step).chain([$t::MIN, -1, 0, 1, $t::MAX]) {
+                for k in 0..$t::BITS {
+                    let modes = [
+                        n.div_rem_trunc_pow2(k),
+                        n.div_rem_floor_pow2(k),
+                        n.div_rem_ceil_pow2(k),
+                        n.div_rem_euclid_pow2(k),
+                        n.div_rem_round_pow2(k),
+                    ];
+                    let quotients = [
+                        n.div_trunc_pow2(k),
+                        n.div_floor_pow2(k),
+                        n.div_ceil_pow2(k),
+                        n.div_euclid_pow2(k),
+                        n.div_round_pow2(k),
+                    ];
+                    let remainders = [
+                        n.rem_trunc_pow2(k),
+                        n.rem_floor_pow2(k),
+                        n.rem_ceil_pow2(k),
+                        n.rem_euclid_pow2(k),
+                        n.rem_round_pow2(k),
+                    ];
+                    for (i, &(q, r)) in reference(n.into(), k).iter().enumerate() {
+                        let expected = ($t::try_from(q).unwrap(), $t::try_from(r).unwrap());
+                        assert_eq!(modes[i], expected, "{} / 2^{}", n, k);
+                        assert_eq!((quotients[i], remainders[i]), expected, "{} / 2^{}", n, k);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_signed!(test_i8, i8, 1);
+test_signed!(test_i16, i16, 7);
+test_signed!(test_i32, i32, 9_999_991);
+test_signed!(test_i64, i64, 0x0f5d_1f2e_7c6b_2a59);
+
+macro_rules! test_unsigned {
+    ($test_name:ident, $t:ident, $step:expr) => {
+        #[test]
+        fn $test_name() {
+            for n in ($t::MIN..=$t::MAX).step_by($step).chain([0, 1, $t::MAX]) {
+                for k in 0..$t::BITS {
+                    let expected = reference(n.into(), k);
+                    let quotients = [
+                        n.div_trunc_pow2(k),
+                        n.div_floor_pow2(k),
+                        n.div_ceil_pow2(k),
+                        n.div_euclid_pow2(k),
+                        n.div_round_pow2(k),
+                    ];
+                    for (&q, &(e, _)) in quotients.iter().zip(&expected) {
+                        assert_eq!(i128::from(q), e, "{} / 2^{}", n, k);
+                    }
+                    let pairs = [
+                        n.checked_div_rem_pow2_by::<Trunc>(k),
+                        n.checked_div_rem_pow2_by::<Floor>(k),
+                        n.checked_div_rem_pow2_by::<Ceil>(k),
+                        n.checked_div_rem_pow2_by::<Euclid>(k),
+                        n.checked_div_rem_pow2_by::<Round>(k),
+                    ];
+                    for (&pair, &(q, r)) in pairs.iter().zip(&expected) {
+                        let expected = $t::try_from(r).ok().map(|r| ($t::try_from(q).unwrap(), r));
+                        assert_eq!(pair, expected, "{} / 2^{}", n, k);
+                    }
+                    assert_eq!(n.div_rem_floor_pow2(k), n.div_rem_floor(1 << k));
+                    assert_eq!(n.rem_euclid_pow2(k), n.euclid_rem(1 << k));
+                }
+            }
+        }
+    };
+}
+
+test_unsigned!(test_u8, u8, 1);
+test_unsigned!(test_u16, u16, 7);
+test_unsigned!(test_u32, u32, 9_999_991);
+test_unsigned!(test_u64, u64, 0x0f5d_1f2e_7c6b_2a59);
+
+#[test]
+fn test_128_bits() {
+    assert_eq!(i128::MIN.div_rem_floor_pow2(127), (-1, 0));
+    assert_eq!(i128::MAX.div_rem_ceil_pow2(127), (1, -1));
+    assert_eq!((-5_i128).div_rem_round_pow2(1), (-3, 1));
+    assert_eq!(u128::MAX.div_round_pow2(127), 2);
+    assert_eq!(u128::MAX.div_rem_euclid_pow2(64), (u128::from(u64::MAX), u128::from(u64::MAX)));
+    assert_eq!((-7_isize).div_rem_euclid_pow2(2), (-2, 1));
+    assert_eq!(7_usize.div_ceil_pow2(2), 2);
+}
+
+#[test]
+fn test_checked() {
+    assert_eq!(1_i32.checked_div_pow2_by::<Floor>(32), None);
+    assert_eq!(1_u8.checked_rem_pow2_by::<Trunc>(8), None);
+    assert_eq!(5_u8.checked_rem_pow2_by::<Ceil>(2), None);
+    assert_eq!(4_u8.checked_rem_pow2_by::<Ceil>(2), Some(0));
+    assert_eq!((-5_i8).checked_div_rem_pow2_by::<Ceil>(7), Some((0, -5)));
+    assert_eq!((-5_i32).checked_div_pow2_by::<HalfEven>(1), Some(-2));
+    assert_eq!((-7_i32).checked_div_pow2_by::<HalfEven>(1), Some(-4));
+    assert_eq!(6_i8.checked_div_rem_pow2_by::<HalfEven>(2), Some((2, -2)));
+    assert_eq!(6_u8.checked_rem_pow2_by::<HalfEven>(2), None);
+    assert_eq!(10_u8.checked_div_rem_pow2_by::<HalfEven>(2), Some((2, 2)));
+}
+
+#[test]
+#[should_panic(expected = "attempt to shift right with overflow")]
+fn test_shift_overflow() {
+    let _ = 1_u64.div_floor_pow2(64);
+}
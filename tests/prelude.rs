@@ -0,0 +1,22 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+
+use std::fmt::Debug;
+use std::num::Wrapping;
+
+use divrem::prelude::*;
+
+fn all_variants<T: DivisionVariants + Copy + PartialEq + Debug>(x: T, y: T) -> [(T, T); 4] {
+    assert_eq!((x.div_floor(y), x.rem_floor(y)), x.div_rem_floor(y));
+    assert_eq!((x.div_euclid(y), x.rem_euclid(y)), x.div_rem_euclid(y));
+    [x.div_rem(y), x.div_rem_floor(y), (x.div_ceil(y), x % y), x.div_rem_euclid(y)]
+}
+
+#[test]
+fn test_division_variants() {
+    assert_eq!(all_variants(-7_i32, 2), [(-3, -1), (-4, 1), (-3, -1), (-4, 1)]);
+    assert_eq!(all_variants(7_u64, 2), [(3, 1), (3, 1), (4, 1), (3, 1)]);
+    assert_eq!(all_variants(Wrapping(7_i8), Wrapping(-2))[3], (Wrapping(-3), Wrapping(1)));
+}
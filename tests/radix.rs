@@ -0,0 +1,152 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+
+use divrem::prelude::*;
+use divrem::radix::{
+    compose, decompose, decompose_ceil, decompose_euclid, decompose_floor, decompose_into_by,
+};
+use divrem::rounding::Floor;
+
+/// Reference decomposition with `i128` arithmetic.
+fn reference<const N: usize>(
+    value: i128,
+    radices: [i128; N],
+    div_rem: fn(i128, i128) -> (i128, i128),
+) -> (i128, [i128; N]) {
+    let mut digits = [0; N];
+    let mut value = value;
+    for (digit, &radix) in digits.iter_mut().zip(&radices).rev() {
+        (value, *digit) = div_rem(value, radix);
+    }
+    (value, digits)
+}
+
+macro_rules! test_small {
+    ($test_name:ident, $t:ident, $radices:expr) => {
+        #[test]
+        fn $test_name() {
+            let narrow = |(high, digits): (i128, [i128; 3])| {
+                Some(($t::try_from(high).ok()?, digits.map(|digit| $t::try_from(digit).unwrap())))
+            };
+            for radices in $radices {
+                let wide = radices.map(i128::from);
+                for value in $t::MIN..=$t::MAX {
+                    let v = i128::from(value);
+                    let results = [
+                        (decompose(value, &radices), reference(v, wide, DivRemExt::trunc_div_rem)),
+                        (
+                            decompose_floor(value, &radices),
+                            reference(v, wide, DivRemExt::floor_div_rem),
+                        ),
+                        (
+                            decompose_euclid(value, &radices),
+                            reference(v, wide, DivRemExt::euclid_div_rem),
+                        ),
+                    ];
+                    for (result, expected) in results {
+                        assert_eq!(result, narrow(expected), "{value} in {radices:?}");
+                        if let Some((high, digits)) = result {
+                            assert_eq!(compose(high, &digits, &radices), Some(value));
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_small!(test_u8, u8, [[2, 3, 5], [1, 1, 1], [255, 1, 7], [16, 16, 16]]);
+test_small!(
+    test_i8,
+    i8,
+    [[2, 3, 5], [-2, -2, -2], [3, -1, -5], [-1, -1, -1], [i8::MIN, 3, i8::MIN], [i8::MAX, -7, 1]]
+);
+
+#[test]
+fn test_ceil() {
+    for radices in [[2, 3, 5], [-2, -2, -2], [3, -1, -5], [i8::MIN, 3, i8::MIN]] {
+        let wide = radices.map(i128::from);
+        for value in i8::MIN..=i8::MAX {
+            let expected = reference(i128::from(value), wide, DivRemExt::ceil_div_rem);
+            let result = decompose_ceil(value, &radices);
+            let expected = i8::try_from(expected.0)
+                .ok()
+                .map(|high| (high, expected.1.map(|d| i8::try_from(d).unwrap())));
+            assert_eq!(result, expected, "{value} in {radices:?}");
+            if let Some((high, digits)) = result {
+                // The products of the ceiled decomposition may overflow.
+                assert_eq!(compose(high, &digits, &radices), Some(value));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_durations() {
+    let radices = [24, 60, 60];
+    assert_eq!(decompose_floor(-90_061_i64, &radices), Some((-2, [22, 58, 59])));
+    assert_eq!(decompose_euclid(-90_061_i64, &radices), Some((-2, [22, 58, 59])));
+    assert_eq!(decompose(-90_061_i64, &radices), Some((-1, [-1, -1, -1])));
+    assert_eq!(decompose_ceil(90_061_i64, &radices), Some((2, [-22, -58, -59])));
+    assert_eq!(compose(-2, &[22, 58, 59], &radices), Some(-90_061_i64));
+    // Negabinary.
+    assert_eq!(decompose_euclid(6, &[-2; 4]), Some((1, [1, 0, 1, 0])));
+    assert_eq!(decompose_euclid(-3, &[-2; 4]), Some((0, [1, 1, 0, 1])));
+    assert_eq!(compose(0, &[1, 1, 0, 1], &[-2; 4]), Some(-3));
+    assert_eq!(decompose_floor(5_u32, &[]), Some((5, [])));
+}
+
+#[test]
+fn test_slices() {
+    let radices = [10_u64; 20];
+    let mut digits = [0; 20];
+    assert_eq!(decompose_into_by::<Floor, _>(u64::MAX, &radices, &mut digits), Some(0));
+    assert_eq!(&digits[..4], [1, 8, 4, 4]);
+    assert_eq!(compose(0, &digits, &radices), Some(u64::MAX));
+    assert_eq!(compose(1, &digits, &radices), None);
+    assert_eq!(
+        decompose_into_by::<Floor, _>(u64::MAX, &radices[..2], &mut digits[..2]),
+        Some(u64::MAX / 100)
+    );
+    assert_eq!(decompose_into_by::<Floor, _>(5, &[3, 0, 2], &mut [0; 3]), None);
+}
+
+#[test]
+fn test_overflow() {
+    assert_eq!(decompose_floor(i64::MIN, &[-1]), None);
+    assert_eq!(decompose_floor(i64::MIN + 1, &[-1]), Some((i64::MAX, [0])));
+    assert_eq!(decompose_euclid(i64::MIN, &[i64::MIN]), Some((1, [0])));
+    assert_eq!(compose(i64::MAX, &[0], &[2]), None);
+    assert_eq!(compose(i64::MAX / 2 + 1, &[-1], &[2]), Some(i64::MAX));
+    assert_eq!(compose(i64::MIN / 2, &[-1], &[2]), None);
+    assert_eq!(compose(i64::MIN / 2 - 1, &[2], &[2]), Some(i64::MIN));
+    assert_eq!(compose(i64::MIN / -2, &[0], &[-2]), Some(i64::MIN));
+}
+
+#[test]
+fn test_compose() {
+    for radix in [i8::MIN, -40, -7, -2, -1, 1, 2, 40, i8::MAX] {
+        for high in i8::MIN..=i8::MAX {
+            for digit in i8::MIN..=i8::MAX {
+                let expected = i128::from(high) * i128::from(radix) + i128::from(digit);
+                let expected = i8::try_from(expected).ok();
+                let result = compose(high, &[digit], &[radix]);
+                assert_eq!(result, expected, "{high} * {radix} + {digit}");
+            }
+        }
+    }
+    // The digits are larger than their radix.
+    assert_eq!(compose(5_i8, &[-120], &[40]), Some(80));
+    assert_eq!(compose(-5_i8, &[120], &[40]), Some(-80));
+}
+
+#[test]
+#[should_panic(expected = "digits and radices have different lengths")]
+fn test_length_mismatch() {
+    let _ = compose(0, &[1, 2], &[3]);
+}
@@ -0,0 +1,123 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+
+use divrem::prelude::*;
+use divrem::rounding::{Euclid, Trunc};
+
+/// Reference floor, ceil and nearest `n`th roots of every `u16`.
+fn roots(n: u32) -> impl Iterator<Item = (u16, [u16; 3])> {
+    let mut r = 0_u128;
+    (0..=u16::MAX).map(move |x| {
+        let wide = u128::from(x);
+        while (r + 1).pow(n) <= wide {
+            r += 1;
+        }
+        let ceil = if r.pow(n) == wide { r } else { r + 1 };
+        let round = if (2 * r + 1).pow(n) <= wide << n { r + 1 } else { r };
+        let narrow = |r: u128| u16::try_from(r).unwrap();
+        (x, [narrow(r), narrow(ceil), narrow(round)])
+    })
+}
+
+#[test]
+fn test_roots() {
+    for n in 1..=16 {
+        for (x, [floor, ceil, round]) in roots(n).step_by(41) {
+            let modes = [x.nth_root_floor(n), x.nth_root_ceil(n), x.nth_root_round(n)];
+            assert_eq!(modes, [Some(floor), Some(ceil), Some(round)], "{n} root of {x}");
+            assert_eq!(x.nth_root_by::<Trunc>(n), Some(floor));
+
+            let (x, [floor, ceil, round]) = (i32::from(x), [floor, ceil, round].map(i32::from));
+            assert_eq!(x.nth_root_by::<Euclid>(n), Some(floor));
+            if n % 2 == 0 {
+                assert_eq!((-x).nth_root_ceil(n), if x == 0 { Some(0) } else { None });
+                continue;
+            }
+            let modes = [(-x).nth_root_floor(n), (-x).nth_root_ceil(n), (-x).nth_root_round(n)];
+            assert_eq!(modes, [Some(-ceil), Some(-floor), Some(-round)], "{n} root of -{x}");
+            assert_eq!((-x).nth_root_by::<Trunc>(n), Some(-floor));
+            assert_eq!((-x).nth_root_by::<Euclid>(n), Some(-ceil));
+        }
+    }
+}
+
+#[test]
+fn test_wide_roots() {
+    let mut x = 0x2545_f491_4f6c_dd1d_u128;
+    for _ in 0..1000 {
+        x = x.wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645).wrapping_add(1);
+        let r = x.isqrt();
+        assert_eq!(x.sqrt_floor(), Some(r));
+        assert_eq!(x.sqrt_ceil(), Some(if r * r == x { r } else { r + 1 }));
+        assert_eq!(x.sqrt_round(), Some(if x > r * r + r { r + 1 } else { r }));
+        let x = x >> 64;
+        let r = x.cbrt_floor().unwrap();
+        assert!(r.pow(3) <= x && x < (r + 1).pow(3));
+        assert_eq!(x.cbrt_ceil(), Some(if r.pow(3) == x { r } else { r + 1 }));
+    }
+}
+
+#[test]
+fn test_edge_cases() {
+    assert_eq!(u128::MAX.sqrt_floor(), Some(u128::from(u64::MAX)));
+    assert_eq!(u128::MAX.sqrt_ceil(), Some(1 << 64));
+    assert_eq!(u128::MAX.sqrt_round(), Some(1 << 64));
+    assert_eq!(i128::MIN.cbrt_floor(), Some(-5_541_191_377_757));
+    assert_eq!(i128::MAX.nth_root_ceil(127), Some(2));
+    assert_eq!((-128_i8).cbrt_floor(), Some(-6));
+    assert_eq!((-128_i8).cbrt_ceil(), Some(-5));
+    assert_eq!((-128_i8).cbrt_round(), Some(-5));
+    assert_eq!(u128::MAX.nth_root_round(218), Some(2));
+    assert_eq!(u128::MAX.nth_root_round(219), Some(1));
+    assert_eq!(2_u8.nth_root_round(u32::MAX), Some(1));
+    assert_eq!(u8::MAX.nth_root_ceil(u32::MAX), Some(2));
+    assert_eq!(0_i64.nth_root_ceil(u32::MAX), Some(0));
+    assert_eq!(5_u32.nth_root_floor(0), None);
+    assert_eq!((-4_i32).sqrt_floor(), None);
+    assert_eq!(7_usize.sqrt_round(), Some(3));
+    assert_eq!((-27_isize).cbrt_ceil(), Some(-3));
+}
+
+#[test]
+fn test_logs() {
+    for base in 2..=20_u16 {
+        let (mut k, mut power) = (0, 1_u128);
+        for x in 1..=u16::MAX {
+            let wide = u128::from(x);
+            while power * u128::from(base) <= wide {
+                power *= u128::from(base);
+                k += 1;
+            }
+            let ceil = if power == wide { k } else { k + 1 };
+            let round = if wide * wide >= power * power * u128::from(base) { ceil } else { k };
+            if x % 11 != 0 {
+                continue;
+            }
+            let modes = [x.ilog_floor(base), x.ilog_ceil(base), x.ilog_round(base)];
+            assert_eq!(modes, [Some(k), Some(ceil), Some(round)], "log {base} of {x}");
+            let x = i32::from(x);
+            assert_eq!(x.ilog_ceil(base.into()), Some(ceil));
+            assert_eq!((-x).ilog_ceil(base.into()), None);
+        }
+    }
+}
+
+#[test]
+fn test_log_edge_cases() {
+    assert_eq!(8_u32.ilog_round(4), Some(2));
+    assert_eq!(7_u32.ilog_round(4), Some(1));
+    assert_eq!(u128::MAX.ilog_ceil(2), Some(128));
+    assert_eq!(u128::MAX.ilog_floor(u128::MAX), Some(1));
+    assert_eq!(u128::MAX.ilog_round(3), Some(81));
+    assert_eq!(10_000_000_000_000_000_000_u64.ilog_ceil(10), Some(19));
+    assert_eq!(i64::MAX.ilog_ceil(10), Some(19));
+    assert_eq!(1_i8.ilog_ceil(2), Some(0));
+    assert_eq!(0_u8.ilog_floor(2), None);
+    assert_eq!(5_u8.ilog_floor(1), None);
+    assert_eq!(5_i8.ilog_floor(-2), None);
+}
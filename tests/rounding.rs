@@ -0,0 +1,45 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+
+use divrem::rounding::{Ceil, Euclid, Floor, Trunc};
+use divrem::{
+    DivBy, DivCeil, DivEuclid, DivFloor, DivRem, DivRemBy, DivRemCeil, DivRemEuclid, DivRemFloor,
+    RemBy, RemCeil, RemEuclid, RemFloor, Rounding,
+};
+
+fn by<M: Rounding>(x: i32, y: i32) -> (i32, i32, (i32, i32))
+where i32: DivBy<M> + RemBy<M> + DivRemBy<M> {
+    (x.div_by(y), x.rem_by(y), x.div_rem_by(y))
+}
+
+macro_rules! test {
+    ($test_name:ident, $mode:ident, $div:expr, $rem:expr, $div_rem:expr) => {
+        #[test]
+        fn $test_name() {
+            for x in -32_i32..32 {
+                for y in (-8_i32..8).filter(|&y| y != 0) {
+                    assert_eq!(by::<$mode>(x, y), ($div(x, y), $rem(x, y), $div_rem(x, y)));
+                }
+            }
+        }
+    };
+}
+
+test!(test_trunc, Trunc, |x, y| x / y, |x, y| x % y, DivRem::div_rem);
+test!(test_floor, Floor, DivFloor::div_floor, RemFloor::rem_floor, DivRemFloor::div_rem_floor);
+test!(test_ceil, Ceil, DivCeil::div_ceil, RemCeil::rem_ceil, DivRemCeil::div_rem_ceil);
+test!(
+    test_euclid,
+    Euclid,
+    DivEuclid::div_euclid,
+    RemEuclid::rem_euclid,
+    DivRemEuclid::div_rem_euclid
+);
+
+#[test]
+fn test_unsigned_div_ceil() {
+    assert_eq!(DivBy::<Ceil>::div_by(7_u32, 2), 4);
+    assert_eq!(DivBy::<Floor, u32>::div_by(&7_u32, 2), 3);
+}
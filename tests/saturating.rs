@@ -0,0 +1,85 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::num::Saturating;
+
+use divrem::prelude::*;
+use divrem::rounding::{Ceil, Euclid, Floor, Trunc};
+use divrem::{DivError, DivRemBy};
+
+macro_rules! test_exhaustive {
+    ($test_name:ident, $t:ident) => {
+        #[test]
+        fn $test_name() {
+            for n in $t::MIN..=$t::MAX {
+                for d in ($t::MIN..=$t::MAX).filter(|&d| d != 0) {
+                    let (x, y) = (Saturating(n), Saturating(d));
+                    let Ok((q, r)) = n.try_div_rem_floor(d) else {
+                        continue;
+                    };
+                    assert_eq!(x.div_rem_floor(y), (Saturating(q), Saturating(r)), "{n} / {d}");
+                    assert_eq!(x.rem_floor(y), Saturating(r));
+                    let (q, r) = n.euclid_div_rem(d);
+                    assert_eq!(x.div_rem_euclid(y), (Saturating(q), Saturating(r)), "{n} / {d}");
+                    assert_eq!(DivCeil::div_ceil(x, y), Saturating(n.ceil_div(d)));
+                    assert_eq!(x.try_div_rem(y), Ok((Saturating(n / d), Saturating(n % d))));
+                }
+            }
+        }
+    };
+}
+
+test_exhaustive!(test_i8, i8);
+test_exhaustive!(test_u8, u8);
+
+#[test]
+fn test_ceil() {
+    for n in i16::MIN..=i16::MAX {
+        for d in [i16::MIN, -300, -7, -1, 1, 2, 7, 300, i16::MAX] {
+            let (x, y) = (Saturating(n), Saturating(d));
+            if let Ok((q, r)) = n.try_div_rem_ceil(d) {
+                assert_eq!(x.div_rem_ceil(y), (Saturating(q), Saturating(r)), "{n} / {d}");
+                assert_eq!(x.rem_ceil(y), Saturating(r));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_saturation() {
+    let (min, minus_one) = (Saturating(i32::MIN), Saturating(-1));
+    let (max, zero) = (Saturating(i32::MAX), Saturating(0));
+    assert_eq!(min.div_rem(minus_one), (max, zero));
+    assert_eq!(DivRemBy::<Trunc>::div_rem_by(min, minus_one), (max, zero));
+    assert_eq!(DivRemBy::<Floor>::div_rem_by(min, minus_one), (max, zero));
+    assert_eq!(DivRemBy::<Ceil>::div_rem_by(min, minus_one), (max, zero));
+    assert_eq!(DivRemBy::<Euclid>::div_rem_by(min, minus_one), (max, zero));
+    assert_eq!(DivCeil::div_ceil(min, minus_one), max);
+    assert_eq!(DivEuclid::div_euclid(min, minus_one), max);
+    assert_eq!(min.rem_floor(minus_one), zero);
+    assert_eq!(min.rem_ceil(minus_one), zero);
+    assert_eq!(RemEuclid::rem_euclid(min, minus_one), zero);
+    assert_eq!(min.try_div_rem_floor(minus_one), Ok((max, zero)));
+    assert_eq!(min.checked_div_euclid(minus_one), Some(max));
+    assert_eq!(min.try_div_floor(minus_one), Ok(max));
+    assert_eq!(min.try_rem(minus_one), Ok(zero));
+    assert_eq!(min.try_div(zero), Err(DivError::DivisionByZero));
+    assert_eq!(min.checked_rem_euclid(zero), None);
+}
+
+#[test]
+fn test_ceil_min_divisor() {
+    assert_eq!((-1_i16).div_rem_ceil(i16::MIN), (1, i16::MAX));
+    assert_eq!((-1_i16).rem_ceil(i16::MIN), i16::MAX);
+    let (x, y) = (Saturating(-1_i16), Saturating(i16::MIN));
+    assert_eq!(x.div_rem_ceil(y), (Saturating(1), Saturating(i16::MAX)));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_zero() {
+    let _ = Saturating(1_u32).div_rem_floor(Saturating(0));
+}
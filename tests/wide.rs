@@ -0,0 +1,62 @@
+#![warn(rust_2018_idioms)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::cargo)]
+#![warn(clippy::nursery)]
+#![allow(clippy::missing_const_for_fn)]
+
+use std::convert::TryFrom;
+
+use divrem::prelude::*;
+
+fn narrow<T: TryFrom<i32>>((q, r): (i32, i32)) -> Option<(T, T)> {
+    Some((T::try_from(q).ok()?, T::try_from(r).ok()?))
+}
+
+#[test]
+fn test_unsigned() {
+    for high in 0..=u8::MAX {
+        for low in (0..=u8::MAX).step_by(17) {
+            let n = i32::from(high) << 8 | i32::from(low);
+            assert_eq!(u8::div_rem_wide(high, low, 0), None);
+            for d in 1..=u8::MAX {
+                let expected = narrow(n.div_rem(i32::from(d)));
+                assert_eq!(u8::div_rem_wide(high, low, d), expected);
+                assert_eq!(u8::div_rem_wide_floor(high, low, d), expected);
+                assert_eq!(u8::div_rem_wide_euclid(high, low, d), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_signed() {
+    for high in i8::MIN..=i8::MAX {
+        for low in (0..=u8::MAX).step_by(17) {
+            let n = i32::from(high) << 8 | i32::from(low);
+            assert_eq!(i8::div_rem_wide_floor(high, low, 0), None);
+            for d in (i8::MIN..=i8::MAX).filter(|&d| d != 0) {
+                let d32 = i32::from(d);
+                assert_eq!(i8::div_rem_wide(high, low, d), narrow(n.div_rem(d32)));
+                assert_eq!(i8::div_rem_wide_floor(high, low, d), narrow(n.floor_div_rem(d32)));
+                assert_eq!(i8::div_rem_wide_ceil(high, low, d), narrow(n.ceil_div_rem(d32)));
+                assert_eq!(i8::div_rem_wide_euclid(high, low, d), narrow(n.euclid_div_rem(d32)));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_u128_by_u64() {
+    let n = u128::MAX / 3;
+    #[allow(clippy::cast_possible_truncation)] // Splitting into words.
+    let (high, low) = ((n >> 64) as u64, n as u64);
+    let d = u64::MAX;
+    let (q, r) = (n / u128::from(d), n % u128::from(d));
+    let expected = (u64::try_from(q).unwrap(), u64::try_from(r).unwrap());
+    assert_eq!(u64::div_rem_wide(high, low, d), Some(expected));
+    assert_eq!(u64::div_rem_wide(high, low, high), None);
+    assert_eq!(i64::div_rem_wide_floor(-1, u64::MAX, 2), Some((-1, 1)));
+    assert_eq!(i64::div_rem_wide_ceil(i64::MIN, 0, i64::MIN), None);
+    assert_eq!(i64::div_rem_wide_ceil(-1, 1, i64::MIN), Some((2, 1)));
+    assert_eq!(i64::div_rem_wide_euclid(-1, 1, i64::MIN), Some((2, 1)));
+}